@@ -1,26 +1,57 @@
 use crate::encode::{Encode, IsNull};
-use oracle::Statement;
+use crate::types::datetime::{from_timestamp_millis, parse_datetime};
+use crate::types::decimal::parse_number;
+use crate::types::json::MAX_JSON_VARCHAR_LEN;
+use crate::types::uuid::{parse_uuid, uuid_text};
+use crate::value::ValueOptions;
+use crate::{OracleBoolEncoding, OracleError, OracleUuidEncoding};
+use oracle::sql_type::{IntervalYM, OracleType, ToSql};
+use oracle::{Batch, Connection, SqlValue, Statement};
 use rbdc::Error;
 use rbs::Value;
-use std::str::FromStr;
 
 #[derive(Debug, Clone)]
 pub enum OracleArgumentValue {
     Null,
+    /// Bound as [`crate::OracleConnectOptions::bool_encoding`] selects.
+    Bool(bool),
     String(String),
     U32(u32),
     U64(u64),
     I32(i32),
     I64(i64),
+    /// Bound as `BINARY_FLOAT`, so NaN and infinities survive.
     F32(f32),
+    /// Bound as `BINARY_DOUBLE`, so NaN and infinities survive.
     F64(f64),
     Binary(Vec<u8>),
     Date(String),
+    /// Any string form of `rbdc::datetime::DateTime`, bound as
+    /// `TIMESTAMP WITH TIME ZONE`.
     DateTime(String),
     Time(String),
     Decimal(String),
+    /// Milliseconds since the Unix epoch, bound as a UTC
+    /// `TIMESTAMP WITH TIME ZONE`.
     Timestamp(i64),
+    /// Any textual UUID form, bound in the layout
+    /// [`crate::OracleConnectOptions::uuid_encoding`] selects, or as the text
+    /// given when none is set.
     Uuid(String),
+    /// JSON text. Bound as `VARCHAR2`, or `CLOB` past 4000 bytes, which both
+    /// `IS JSON` columns and 21c+ native `JSON` columns accept.
+    Json(String),
+    /// `ROWID` or `UROWID` text. Bound as `VARCHAR2`, which Oracle converts
+    /// implicitly in comparisons such as `WHERE rowid = :1`.
+    RowId(String),
+    /// `INTERVAL YEAR TO MONTH`, as a signed number of months.
+    IntervalYM(i64),
+    /// `INTERVAL DAY TO SECOND`.
+    IntervalDS(chrono::Duration),
+    /// OUT bind of the given type; its value is read back after execution.
+    Out(OracleType),
+    /// IN OUT bind: sends the value, then reads it back as the given type.
+    InOut(Box<OracleArgumentValue>, OracleType),
 }
 
 #[derive(Default, Debug, Clone)]
@@ -39,6 +70,45 @@ impl OracleArguments {
         Ok(())
     }
 
+    /// Adds an OUT bind, e.g. for `begin :1 := f(); end;`.
+    pub fn add_out(&mut self, oracle_type: OracleType) {
+        self.values.push(OracleArgumentValue::Out(oracle_type));
+    }
+
+    /// Adds an IN OUT bind that sends `value` and reads back `oracle_type`.
+    pub fn add_in_out<T>(&mut self, value: T, oracle_type: OracleType) -> Result<(), Error>
+    where
+        T: Encode,
+    {
+        let mut values = Vec::with_capacity(1);
+        if let IsNull::Yes = value.encode(&mut values)? {
+            values.push(OracleArgumentValue::Null);
+        }
+        match <[OracleArgumentValue; 1]>::try_from(values) {
+            Ok([value]) => {
+                self.values
+                    .push(OracleArgumentValue::InOut(Box::new(value), oracle_type));
+                Ok(())
+            }
+            Err(_) => Err(Error::from("IN OUT bind must encode to a single value")),
+        }
+    }
+
+    /// Declared types of the OUT and IN OUT binds in `offset..offset + len`,
+    /// `None` for plain IN binds.
+    pub(crate) fn out_types(&self, offset: usize, len: usize) -> Vec<Option<OracleType>> {
+        self.values
+            .iter()
+            .skip(offset)
+            .take(len)
+            .map(|value| match value {
+                OracleArgumentValue::Out(oracle_type)
+                | OracleArgumentValue::InOut(_, oracle_type) => Some(oracle_type.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
     pub fn from_args(args: Vec<Value>) -> Result<Self, Error> {
         let mut arguments = Self {
             values: Vec::with_capacity(args.len()),
@@ -70,14 +140,67 @@ impl OracleArguments {
         &self.values
     }
 
-    pub(super) fn bind(&self, statement: &mut Statement, offset: usize) -> Result<usize, Error> {
+    pub(super) fn bind(
+        &self,
+        statement: &mut Statement,
+        offset: usize,
+        options: ValueOptions,
+    ) -> Result<usize, Error> {
         let mut used = 0;
         for (index, value) in self.values.iter().enumerate().skip(offset) {
-            value.bind(statement, index + 1 - offset)?;
+            value.bind(statement, index + 1 - offset, options)?;
             used += 1;
         }
         Ok(used)
     }
+
+    /// Appends every row to `batch` as one array bind.
+    ///
+    /// Bind types are taken from the first non-null value of each column, so a
+    /// leading `NULL` does not pin the whole column to `VARCHAR2`.
+    pub(crate) fn bind_batch(
+        rows: &[OracleArguments],
+        conn: &Connection,
+        batch: &mut Batch,
+        options: ValueOptions,
+    ) -> Result<(), Error> {
+        check_batch_rows(rows, batch.bind_count())?;
+        for index in 0..batch.bind_count() {
+            let first = rows.iter().find_map(|row| match row.values.get(index) {
+                Some(OracleArgumentValue::Null) | None => None,
+                Some(value) => Some(value),
+            });
+            if let Some(value) = first {
+                value.with_sql(options, |value| {
+                    batch.set_type(index + 1, &value.oratype(conn)?)
+                })?;
+            }
+        }
+
+        for row in rows {
+            for (index, value) in row.values.iter().enumerate() {
+                value.with_sql(options, |value| batch.set(index + 1, value))?;
+            }
+            batch
+                .append_row(&[])
+                .map_err(|e| Error::from(OracleError::from(e)))?;
+        }
+        Ok(())
+    }
+}
+
+/// Rejects rows that do not supply exactly one value per placeholder, which
+/// would otherwise leave binds NULL or fail on an out-of-range index.
+fn check_batch_rows(rows: &[OracleArguments], bind_count: usize) -> Result<(), Error> {
+    for (index, row) in rows.iter().enumerate() {
+        if row.values.len() != bind_count {
+            return Err(Error::from(format!(
+                "batch row {index} has {} values but the statement has {bind_count} binds",
+                row.values.len()
+            )));
+        }
+    }
+    Ok(())
 }
 
 impl OracleArgumentValue {
@@ -85,67 +208,123 @@ impl OracleArgumentValue {
         self
     }
 
-    fn bind(&self, statement: &mut Statement, index: usize) -> Result<(), Error> {
+    fn bind(
+        &self,
+        statement: &mut Statement,
+        index: usize,
+        options: ValueOptions,
+    ) -> Result<(), Error> {
+        self.with_sql(options, |value| statement.bind(index, value))
+    }
+
+    /// Converts the value into its bindable form and hands it to `f`.
+    fn with_sql<R>(
+        &self,
+        options: ValueOptions,
+        f: impl FnOnce(&dyn ToSql) -> oracle::Result<R>,
+    ) -> Result<R, Error> {
         match self {
-            OracleArgumentValue::Null => statement
-                .bind(index, &Option::<String>::None)
-                .map_err(|e| Error::from(e.to_string()))?,
-            OracleArgumentValue::String(value) => statement
-                .bind(index, value)
-                .map_err(|e| Error::from(e.to_string()))?,
-            OracleArgumentValue::U32(value) => statement
-                .bind(index, value)
-                .map_err(|e| Error::from(e.to_string()))?,
-            OracleArgumentValue::U64(value) => statement
-                .bind(index, value)
-                .map_err(|e| Error::from(e.to_string()))?,
-            OracleArgumentValue::I32(value) => statement
-                .bind(index, value)
-                .map_err(|e| Error::from(e.to_string()))?,
-            OracleArgumentValue::I64(value) => statement
-                .bind(index, value)
-                .map_err(|e| Error::from(e.to_string()))?,
-            OracleArgumentValue::F32(value) => statement
-                .bind(index, value)
-                .map_err(|e| Error::from(e.to_string()))?,
-            OracleArgumentValue::F64(value) => statement
-                .bind(index, value)
-                .map_err(|e| Error::from(e.to_string()))?,
-            OracleArgumentValue::Binary(value) => statement
-                .bind(index, value)
-                .map_err(|e| Error::from(e.to_string()))?,
+            OracleArgumentValue::InOut(value, oracle_type) => {
+                value.with_plain_sql(options, |value| f(&InOutSql { value, oracle_type }))
+            }
+            value => value.with_plain_sql(options, f),
+        }
+    }
+
+    fn with_plain_sql<R>(
+        &self,
+        options: ValueOptions,
+        f: impl FnOnce(&dyn ToSql) -> oracle::Result<R>,
+    ) -> Result<R, Error> {
+        let result = match self {
+            OracleArgumentValue::Null => f(&Option::<String>::None),
+            OracleArgumentValue::Bool(value) => match options.bools {
+                OracleBoolEncoding::Native => f(value),
+                OracleBoolEncoding::Number => f(&i32::from(*value)),
+                encoding => f(&encoding.literal(*value)),
+            },
+            OracleArgumentValue::String(value) => f(value),
+            OracleArgumentValue::U32(value) => f(value),
+            OracleArgumentValue::U64(value) => f(value),
+            OracleArgumentValue::I32(value) => f(value),
+            OracleArgumentValue::I64(value) => f(value),
+            OracleArgumentValue::F32(value) => f(&(value, &OracleType::BinaryFloat)),
+            OracleArgumentValue::F64(value) => f(&(value, &OracleType::BinaryDouble)),
+            OracleArgumentValue::Binary(value) => f(value),
             OracleArgumentValue::Date(value) => {
                 let value = chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
                     .map_err(|e| Error::from(e.to_string()))?;
-                statement
-                    .bind(index, &value)
-                    .map_err(|e| Error::from(e.to_string()))?;
-            }
-            OracleArgumentValue::DateTime(value) => {
-                let value = chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f%z")
-                    .map_err(|e| Error::from(e.to_string()))?;
-                statement
-                    .bind(index, &value)
-                    .map_err(|e| Error::from(e.to_string()))?;
+                f(&value)
             }
-            OracleArgumentValue::Time(value) => statement
-                .bind(index, value)
-                .map_err(|e| Error::from(e.to_string()))?,
+            OracleArgumentValue::DateTime(value) => f(&parse_datetime(value)?),
+            OracleArgumentValue::Time(value) => f(value),
+            // A string bound as NUMBER is converted by ODPI-C itself, so the
+            // session's decimal separator does not matter.
             OracleArgumentValue::Decimal(value) => {
-                let value = bigdecimal::BigDecimal::from_str(value)
-                    .map_err(|e| Error::from(e.to_string()))?
-                    .to_string();
-                statement
-                    .bind(index, &value)
-                    .map_err(|e| Error::from(e.to_string()))?;
+                let value = parse_number(value)?.to_plain_string();
+                f(&(&value, &OracleType::Number(0, -127)))
             }
-            OracleArgumentValue::Timestamp(value) => statement
-                .bind(index, value)
-                .map_err(|e| Error::from(e.to_string()))?,
-            OracleArgumentValue::Uuid(value) => statement
-                .bind(index, value)
-                .map_err(|e| Error::from(e.to_string()))?,
-        }
-        Ok(())
+            OracleArgumentValue::Timestamp(value) => f(&from_timestamp_millis(*value)?),
+            OracleArgumentValue::Uuid(value) => match options.uuids {
+                Some(OracleUuidEncoding::Raw16) => f(&parse_uuid(value)?.as_bytes().to_vec()),
+                Some(encoding) => f(&uuid_text(&parse_uuid(value)?, encoding)),
+                None => f(value),
+            },
+            OracleArgumentValue::Json(value) if value.len() > MAX_JSON_VARCHAR_LEN => {
+                f(&(value, &OracleType::CLOB))
+            }
+            OracleArgumentValue::Json(value) => f(value),
+            OracleArgumentValue::RowId(value) => f(value),
+            OracleArgumentValue::IntervalYM(months) => {
+                let years = i32::try_from(months / 12)
+                    .map_err(|_| Error::from(format!("interval out of range: {months} months")))?;
+                let value = IntervalYM::new(years, (months % 12) as i32)
+                    .map_err(|e| Error::from(OracleError::from(e)))?;
+                f(&value)
+            }
+            OracleArgumentValue::IntervalDS(value) => f(value),
+            OracleArgumentValue::Out(oracle_type) => f(oracle_type),
+            OracleArgumentValue::InOut(..) => {
+                return Err(Error::from("IN OUT binds cannot be nested"));
+            }
+        };
+        result.map_err(|e| Error::from(OracleError::from(e)))
+    }
+}
+
+/// Binds `value` with an explicit type so it can be written back by PL/SQL.
+struct InOutSql<'a> {
+    value: &'a dyn ToSql,
+    oracle_type: &'a OracleType,
+}
+
+impl ToSql for InOutSql<'_> {
+    fn oratype(&self, _conn: &Connection) -> oracle::Result<OracleType> {
+        Ok(self.oracle_type.clone())
+    }
+
+    fn to_sql(&self, val: &mut SqlValue) -> oracle::Result<()> {
+        self.value.to_sql(val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check_batch_rows;
+    use crate::OracleArguments;
+    use rbs::Value;
+
+    #[test]
+    fn test_check_batch_rows() {
+        let row =
+            |len: i32| OracleArguments::from_args((0..len).map(Value::I32).collect()).unwrap();
+        assert!(check_batch_rows(&[row(2), row(2)], 2).is_ok());
+
+        let error = check_batch_rows(&[row(2), row(1)], 2).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "batch row 1 has 1 values but the statement has 2 binds"
+        );
+        assert!(check_batch_rows(&[row(3)], 2).is_err());
     }
 }
@@ -1,6 +1,9 @@
-use crate::OracleConnectOptions;
 use crate::connection::{ConnectionHandle, ConnectionState, Statements};
-use oracle::Connection as RawOracleConnection;
+use crate::value::ValueOptions;
+use crate::{
+    OracleBoolEncoding, OracleConnectOptions, OracleError, OracleIsolationLevel, OracleUuidEncoding,
+};
+use oracle::{Connection as RawOracleConnection, Version};
 use rbdc::Error;
 use std::sync::atomic::{AtomicU64, Ordering};
 
@@ -12,6 +15,12 @@ pub(crate) struct EstablishParams {
     password: String,
     connect_string: String,
     statement_cache_capacity: usize,
+    autocommit: bool,
+    isolation_level: Option<OracleIsolationLevel>,
+    bool_fallback: OracleBoolEncoding,
+    bool_encoding: Option<OracleBoolEncoding>,
+    uuid_encoding: Option<OracleUuidEncoding>,
+    prefer_decimal: bool,
     pub(crate) thread_name: String,
     pub(crate) command_channel_size: usize,
 }
@@ -23,6 +32,12 @@ impl EstablishParams {
             password: options.password.clone(),
             connect_string: options.connect_string.clone(),
             statement_cache_capacity: options.statement_cache_capacity,
+            autocommit: options.autocommit,
+            isolation_level: options.isolation_level,
+            bool_fallback: options.bool_fallback,
+            bool_encoding: options.bool_encoding,
+            uuid_encoding: options.uuid_encoding,
+            prefer_decimal: options.prefer_decimal,
             thread_name: format!(
                 "rbdc-oracle-worker-{}",
                 THREAD_ID.fetch_add(1, Ordering::AcqRel)
@@ -34,12 +49,38 @@ impl EstablishParams {
     pub(crate) fn establish(&self) -> Result<ConnectionState, Error> {
         let connection =
             RawOracleConnection::connect(&self.username, &self.password, &self.connect_string)
-                .map_err(|e| Error::from(e.to_string()))?;
+                .map_err(|e| Error::from(OracleError::from(e)))?;
+        let value_options = ValueOptions {
+            bools: self.bool_encoding(&connection)?,
+            stored_bools: self.bool_encoding,
+            uuids: self.uuid_encoding,
+            prefer_decimal: self.prefer_decimal,
+        };
 
         Ok(ConnectionState {
             handle: ConnectionHandle::new(connection),
-            transaction_active: false,
+            transaction_depth: 0,
+            autocommit: self.autocommit,
+            default_isolation: self.isolation_level,
+            isolation: None,
+            value_options,
             statements: Statements::new(self.statement_cache_capacity),
         })
     }
+
+    /// SQL `BOOLEAN` binds need Oracle 23ai on both ends of the session.
+    fn bool_encoding(&self, connection: &RawOracleConnection) -> Result<OracleBoolEncoding, Error> {
+        if let Some(encoding) = self.bool_encoding {
+            return Ok(encoding);
+        }
+        let (server, _) = connection
+            .server_version()
+            .map_err(|e| Error::from(OracleError::from(e)))?;
+        let client = Version::client().map_err(|e| Error::from(OracleError::from(e)))?;
+        if server.major() >= 23 && client.major() >= 23 {
+            Ok(OracleBoolEncoding::Native)
+        } else {
+            Ok(self.bool_fallback)
+        }
+    }
 }
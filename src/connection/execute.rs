@@ -1,19 +1,27 @@
 use crate::connection::ConnectionState;
-use crate::{OracleArguments, OracleQueryResult, OracleRow};
+use crate::connection::executor::{self, Execution, OutTypes, QueryRows};
+use crate::statement::PreparedStatement;
+use crate::value::ValueOptions;
+use crate::{OracleArguments, OracleError, OracleIsolationLevel, OracleQueryResult, OracleRow};
 use either::Either;
+use oracle::sql_type::OracleType;
 use rbdc::Error;
 use std::collections::VecDeque;
 
 pub struct ExecuteIter<'a> {
     handle: &'a mut crate::connection::ConnectionHandle,
-    transaction_active: &'a mut bool,
+    transaction_depth: &'a mut usize,
+    autocommit: bool,
+    default_isolation: Option<OracleIsolationLevel>,
+    isolation: &'a mut Option<OracleIsolationLevel>,
+    value_options: ValueOptions,
     query: &'a str,
-    statement: &'a mut crate::statement::VirtualStatement,
+    statement: Option<PreparedStatement<'a>>,
     args: Option<OracleArguments>,
     args_used: usize,
-    goto_next: bool,
     finished: bool,
-    buffered: VecDeque<Result<Either<OracleQueryResult, OracleRow>, Error>>,
+    /// Result sets still to be streamed, in order.
+    rows: VecDeque<QueryRows<'a>>,
 }
 
 pub(crate) fn iter<'a>(
@@ -22,44 +30,290 @@ pub(crate) fn iter<'a>(
     args: Option<OracleArguments>,
     persistent: bool,
 ) -> Result<ExecuteIter<'a>, Error> {
-    let statement = conn.statements.get(query, persistent)?;
+    // Transaction control is run by the driver itself, so there is nothing to
+    // build or cache for it.
+    let statement = match transaction_command(query) {
+        Some(_) => None,
+        None => conn
+            .statements
+            .get(query, persistent)?
+            .prepare_next(&mut conn.handle)?,
+    };
 
     Ok(ExecuteIter {
         handle: &mut conn.handle,
-        transaction_active: &mut conn.transaction_active,
+        transaction_depth: &mut conn.transaction_depth,
+        autocommit: conn.autocommit,
+        default_isolation: conn.default_isolation,
+        isolation: &mut conn.isolation,
+        value_options: conn.value_options,
         query,
         statement,
         args,
         args_used: 0,
-        goto_next: true,
         finished: false,
-        buffered: VecDeque::with_capacity(1),
+        rows: VecDeque::new(),
     })
 }
 
 fn bind(
     statement: &mut oracle::Statement,
+    query: &str,
     arguments: &Option<OracleArguments>,
     offset: usize,
-) -> Result<usize, Error> {
+    options: ValueOptions,
+) -> Result<(usize, OutTypes), Error> {
     let mut used = 0;
+    let mut out_types = Vec::new();
     if let Some(arguments) = arguments {
-        used = arguments.bind(statement, offset)?;
+        used = arguments.bind(statement, offset, options)?;
+        out_types = arguments.out_types(offset, used);
     }
-    Ok(used)
+
+    // `RETURNING ... INTO` placeholders left without an argument are bound as
+    // OUT NUMBERs, the usual shape of a generated key; other types can be
+    // declared with an explicit OUT argument. Missing arguments before that
+    // list stay unbound, so Oracle reports them instead of inserting NULL.
+    if statement.is_returning() {
+        let oracle_type = OracleType::Number(0, -127);
+        let bind_count = statement.bind_count();
+        let returning = bind_count.saturating_sub(returning_bind_count(query));
+        for index in used + 1..=bind_count {
+            if index <= returning {
+                out_types.push(None);
+                continue;
+            }
+            statement
+                .bind(index, &oracle_type)
+                .map_err(|e| Error::from(OracleError::from(e)))?;
+            out_types.push(Some(oracle_type.clone()));
+        }
+    }
+    Ok((used, out_types))
+}
+
+/// Number of placeholders in the `RETURNING ... INTO` list, which always
+/// closes a DML statement. ODPI-C counts every occurrence of a SQL bind
+/// name, so a repeated name counts once per occurrence.
+fn returning_bind_count(query: &str) -> usize {
+    let code = blank_comments_and_literals(query);
+    let words: Vec<&str> = code
+        .split(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | '$' | '#' | ':')))
+        .filter(|word| !word.is_empty())
+        .collect();
+
+    let Some(returning) = words
+        .iter()
+        .rposition(|word| matches!(*word, "returning" | "return"))
+    else {
+        return 0;
+    };
+    let Some(into) = words[returning..].iter().position(|word| *word == "into") else {
+        return 0;
+    };
+    words[returning + into..]
+        .iter()
+        .filter(|word| word.starts_with(':'))
+        .count()
+}
+
+/// Lower-cases `query` and blanks out comments, string literals (including
+/// `q'[...]'` literals) and quoted identifiers, so their contents are never
+/// taken for keywords or placeholders.
+fn blank_comments_and_literals(query: &str) -> String {
+    let chars: Vec<char> = query.chars().collect();
+    let mut code = String::with_capacity(query.len());
+    let mut i = 0;
+    let is_word = |c: char| c.is_alphanumeric() || matches!(c, '_' | '$' | '#');
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        let end = if c == '-' && next == Some('-') {
+            chars[i..]
+                .iter()
+                .position(|&c| c == '\n')
+                .map_or(chars.len(), |n| i + n)
+        } else if c == '/' && next == Some('*') {
+            chars[i + 2..]
+                .windows(2)
+                .position(|pair| pair == ['*', '/'])
+                .map_or(chars.len(), |n| i + 2 + n + 2)
+        } else if matches!(c, 'q' | 'Q')
+            && next == Some('\'')
+            && i + 2 < chars.len()
+            && (i == 0
+                || !is_word(chars[i - 1])
+                || (matches!(chars[i - 1], 'n' | 'N') && (i == 1 || !is_word(chars[i - 2]))))
+        {
+            let close = match chars[i + 2] {
+                '[' => ']',
+                '(' => ')',
+                '{' => '}',
+                '<' => '>',
+                delimiter => delimiter,
+            };
+            chars[i + 3..]
+                .windows(2)
+                .position(|pair| pair == [close, '\''])
+                .map_or(chars.len(), |n| i + 3 + n + 2)
+        } else if matches!(c, '\'' | '"') {
+            // A doubled quote inside a literal closes and reopens it, which
+            // blanks the same characters.
+            chars[i + 1..]
+                .iter()
+                .position(|&quote| quote == c)
+                .map_or(chars.len(), |n| i + 1 + n + 1)
+        } else {
+            code.push(c.to_ascii_lowercase());
+            i += 1;
+            continue;
+        };
+        code.extend(std::iter::repeat_n(' ', end - i));
+        i = end;
+    }
+    code
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum TransactionCommand<'q> {
+    /// `begin`, optionally followed by an isolation level or `read only`.
+    Begin(Option<OracleIsolationLevel>),
+    Commit,
+    Rollback,
+    Savepoint(&'q str),
+    RollbackTo(&'q str),
+    Release(&'q str),
 }
 
-fn transaction_command(query: &str) -> Option<&'static str> {
-    let query = query.trim();
-
-    if query.eq_ignore_ascii_case("begin") {
-        Some("begin")
-    } else if query.eq_ignore_ascii_case("commit") {
-        Some("commit")
-    } else if query.eq_ignore_ascii_case("rollback") {
-        Some("rollback")
-    } else {
-        None
+fn transaction_command(query: &str) -> Option<TransactionCommand<'_>> {
+    let words: Vec<&str> = query.split_ascii_whitespace().collect();
+    let keyword = |index: usize, expected: &str| {
+        words
+            .get(index)
+            .is_some_and(|word| word.eq_ignore_ascii_case(expected))
+    };
+    // `rollback to savepoint sp` and `release savepoint sp` name it last.
+    let name = || {
+        let name = *words.last()?;
+        is_savepoint_name(name).then_some(name)
+    };
+
+    match words.len() {
+        1 if keyword(0, "begin") => Some(TransactionCommand::Begin(None)),
+        2.. if keyword(0, "begin") => words[1..]
+            .join(" ")
+            .parse()
+            .ok()
+            .map(|level| TransactionCommand::Begin(Some(level))),
+        1 if keyword(0, "commit") => Some(TransactionCommand::Commit),
+        1 if keyword(0, "rollback") => Some(TransactionCommand::Rollback),
+        2 if keyword(0, "savepoint") => name().map(TransactionCommand::Savepoint),
+        2 if keyword(0, "release") => name().map(TransactionCommand::Release),
+        3 if keyword(0, "release") && keyword(1, "savepoint") => {
+            name().map(TransactionCommand::Release)
+        }
+        3 if keyword(0, "rollback") && keyword(1, "to") => {
+            name().map(TransactionCommand::RollbackTo)
+        }
+        4 if keyword(0, "rollback") && keyword(1, "to") && keyword(2, "savepoint") => {
+            name().map(TransactionCommand::RollbackTo)
+        }
+        _ => None,
+    }
+}
+
+/// Accepts plain and double-quoted Oracle identifiers.
+pub(crate) fn is_savepoint_name(name: &str) -> bool {
+    if let Some(quoted) = name
+        .strip_prefix('"')
+        .and_then(|name| name.strip_suffix('"'))
+    {
+        return !quoted.is_empty() && !quoted.contains('"');
+    }
+
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '$' | '#'))
+}
+
+/// Savepoint standing in for a nested `begin` at the given depth.
+fn nested_savepoint(depth: usize) -> String {
+    format!("rbdc_savepoint_{depth}")
+}
+
+impl ExecuteIter<'_> {
+    /// Runs `command`, mapping nested `begin`/`commit`/`rollback` onto
+    /// savepoints so inner transactions never end the outer one.
+    fn run_transaction_command(&mut self, command: TransactionCommand<'_>) -> Result<(), Error> {
+        let connection = self.handle.connection();
+        let depth = *self.transaction_depth;
+        let execute = |sql: String| {
+            connection
+                .execute(&sql, &[])
+                .map(|_| ())
+                .map_err(|e| Error::from(OracleError::from(e)))
+        };
+
+        match command {
+            TransactionCommand::Begin(level) if depth > 0 => {
+                // The mode is fixed by the outer transaction and cannot change
+                // once it has started.
+                if let Some(level) = level
+                    && *self.isolation != Some(level)
+                {
+                    return Err(Error::from(format!(
+                        "cannot begin a nested {level} transaction inside an active transaction"
+                    )));
+                }
+                execute(format!("savepoint {}", nested_savepoint(depth)))?;
+                *self.transaction_depth = depth + 1;
+            }
+            TransactionCommand::Begin(level) => {
+                let level = level.or(self.default_isolation);
+                if let Some(level) = level {
+                    execute(level.set_transaction_sql().to_owned())?;
+                }
+                *self.isolation = level;
+                *self.transaction_depth = 1;
+            }
+            TransactionCommand::Commit if depth > 1 => {
+                *self.transaction_depth = depth - 1;
+            }
+            TransactionCommand::Commit => {
+                connection
+                    .commit()
+                    .map_err(|e| Error::from(OracleError::from(e)))?;
+                *self.transaction_depth = 0;
+                *self.isolation = None;
+            }
+            TransactionCommand::Rollback if depth > 1 => {
+                execute(format!(
+                    "rollback to savepoint {}",
+                    nested_savepoint(depth - 1)
+                ))?;
+                *self.transaction_depth = depth - 1;
+            }
+            TransactionCommand::Rollback => {
+                connection
+                    .rollback()
+                    .map_err(|e| Error::from(OracleError::from(e)))?;
+                *self.transaction_depth = 0;
+                *self.isolation = None;
+            }
+            TransactionCommand::Savepoint(name) => {
+                execute(format!("savepoint {name}"))?;
+                // A savepoint only lives inside a transaction, so stop
+                // committing after each statement.
+                *self.transaction_depth = depth.max(1);
+            }
+            TransactionCommand::RollbackTo(name) => {
+                execute(format!("rollback to savepoint {name}"))?;
+            }
+            // Oracle has no RELEASE SAVEPOINT; savepoints end with the
+            // transaction.
+            TransactionCommand::Release(_) => {}
+        }
+        Ok(())
     }
 }
 
@@ -71,77 +325,158 @@ impl Iterator for ExecuteIter<'_> {
             return None;
         }
 
-        if let Some(item) = self.buffered.pop_front() {
-            return Some(item);
+        while let Some(rows) = self.rows.front_mut() {
+            if let Some(item) = rows.next() {
+                return Some(item);
+            }
+            self.rows.pop_front();
         }
 
-        if self.goto_next {
-            if let Some(command) = transaction_command(self.query) {
-                self.finished = true;
-
-                if command == "begin" {
-                    *self.transaction_active = true;
-                    return Some(Ok(Either::Left(OracleQueryResult::default())));
-                }
-
-                let result = match command {
-                    "commit" => self.handle.connection().commit(),
-                    "rollback" => self.handle.connection().rollback(),
-                    _ => unreachable!(),
-                }
-                .map_err(|e| Error::from(e.to_string()))
-                .map(|_| {
-                    *self.transaction_active = false;
-                    Either::Left(OracleQueryResult::default())
-                });
-                return Some(result);
-            }
+        if let Some(command) = transaction_command(self.query) {
+            self.finished = true;
+            return Some(
+                self.run_transaction_command(command)
+                    .map(|_| Either::Left(OracleQueryResult::default())),
+            );
         }
 
-        let prepared = if self.goto_next {
-            let prepared = match self.statement.prepare_next(self.handle) {
-                Ok(Some(statement)) => statement,
-                Ok(None) => return None,
-                Err(e) => return Some(Err(e)),
-            };
-            self.goto_next = false;
-            prepared
-        } else {
-            self.statement.current()?
-        };
+        let prepared = self.statement.take()?;
+
+        // Outside a transaction the commit rides on the execute call itself.
+        // Queries never commit: there is nothing to persist, and committing
+        // would invalidate `SELECT ... FOR UPDATE` cursors mid-fetch.
+        let commit = self.autocommit
+            && *self.transaction_depth == 0
+            && !prepared.handle.statement().is_query();
+        self.handle.set_commit_on_success(commit);
+        let result = executor::run_prepared(prepared, self.value_options, |statement| {
+            bind(
+                statement,
+                self.query,
+                &self.args,
+                self.args_used,
+                self.value_options,
+            )
+        });
+        self.handle.set_commit_on_success(false);
 
-        let items = match super::executor::run_prepared(
-            self.handle,
-            prepared.handle.sql(),
-            prepared.columns,
-            |statement| bind(statement, &self.args, self.args_used),
-        ) {
-            Ok((items, args_used)) => {
+        let execution = match result {
+            Ok((execution, args_used)) => {
                 self.args_used += args_used;
-                items
+                execution
             }
             Err(e) => return Some(Err(e)),
         };
 
-        if !*self.transaction_active {
-            if let Err(e) = self
-                .handle
-                .connection()
-                .commit()
-                .map_err(|e| Error::from(e.to_string()))
-            {
-                return Some(Err(e));
+        match execution {
+            Execution::Rows(rows) => {
+                self.rows.push_back(rows);
+                self.next()
+            }
+            Execution::Done(result, cursors) => {
+                self.rows.extend(cursors);
+                Some(Ok(Either::Left(result)))
             }
         }
-
-        self.goto_next = true;
-        self.buffered = items.into();
-        self.buffered.pop_front()
     }
 }
 
-impl Drop for ExecuteIter<'_> {
-    fn drop(&mut self) {
-        self.statement.reset().ok();
+#[cfg(test)]
+mod tests {
+    use super::{TransactionCommand, returning_bind_count, transaction_command};
+    use crate::OracleIsolationLevel;
+
+    #[test]
+    fn test_transaction_command() {
+        assert_eq!(
+            transaction_command(" BEGIN "),
+            Some(TransactionCommand::Begin(None))
+        );
+        assert_eq!(
+            transaction_command("begin isolation level SERIALIZABLE"),
+            Some(TransactionCommand::Begin(Some(
+                OracleIsolationLevel::Serializable
+            )))
+        );
+        assert_eq!(
+            transaction_command("begin read only"),
+            Some(TransactionCommand::Begin(Some(
+                OracleIsolationLevel::ReadOnly
+            )))
+        );
+        assert_eq!(
+            transaction_command("commit"),
+            Some(TransactionCommand::Commit)
+        );
+        assert_eq!(
+            transaction_command("savepoint sp_1"),
+            Some(TransactionCommand::Savepoint("sp_1"))
+        );
+        assert_eq!(
+            transaction_command("ROLLBACK TO SAVEPOINT sp_1"),
+            Some(TransactionCommand::RollbackTo("sp_1"))
+        );
+        assert_eq!(
+            transaction_command("rollback to \"MixedCase\""),
+            Some(TransactionCommand::RollbackTo("\"MixedCase\""))
+        );
+        assert_eq!(
+            transaction_command("release savepoint sp_1"),
+            Some(TransactionCommand::Release("sp_1"))
+        );
+    }
+
+    #[test]
+    fn test_transaction_command_passthrough() {
+        assert_eq!(transaction_command("begin null; end;"), None);
+        assert_eq!(transaction_command("savepoint 1sp"), None);
+        assert_eq!(transaction_command("select 1 from dual"), None);
+    }
+
+    #[test]
+    fn test_returning_bind_count() {
+        assert_eq!(
+            returning_bind_count("insert into t(a, b) values (:1, :2) returning id into :3"),
+            1
+        );
+        assert_eq!(
+            returning_bind_count(
+                "UPDATE t SET a = :a WHERE b = 'returning x into :y' RETURNING id, a INTO :id, :out_a"
+            ),
+            2
+        );
+        assert_eq!(returning_bind_count("insert into t(a) values (:1)"), 0);
+    }
+
+    #[test]
+    fn test_returning_bind_count_repeated_names() {
+        assert_eq!(
+            returning_bind_count("update t set a = :a returning id, id into :x, :x"),
+            2
+        );
+    }
+
+    #[test]
+    fn test_returning_bind_count_skips_comments_and_q_literals() {
+        assert_eq!(
+            returning_bind_count(
+                "update t set a = 1 -- returning id into :gone\n returning id /* , a */ into :id /* , :a */"
+            ),
+            1
+        );
+        assert_eq!(
+            returning_bind_count(
+                "update t set a = q'[it's returning x into :y]' returning id into :id"
+            ),
+            1
+        );
+        assert_eq!(
+            returning_bind_count("update t set a = nq'{ :y }' returning id, a into :id, :a"),
+            2
+        );
+        assert_eq!(
+            returning_bind_count("-- returning id into :id\ninsert into t values (1)"),
+            0
+        );
     }
 }
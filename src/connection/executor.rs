@@ -1,19 +1,27 @@
 use crate::connection::ConnectionState;
+use crate::decode::Decode;
 use crate::query::OracleQuery;
+use crate::statement::PreparedStatement;
+use crate::types::bytes::is_binary;
+use crate::types::datetime::{is_datetime, timestamp_text};
+use crate::types::interval::{interval_ds_text, interval_ym_months, interval_ym_text};
+use crate::value::ValueOptions;
 use crate::{
-    OracleColumn, OracleQueryResult, OracleRow, OracleStatement, OracleTypeInfo, OracleValue,
+    OracleArguments, OracleBatchError, OracleColumn, OracleError, OracleQueryResult, OracleRow,
+    OracleStatement, OracleTypeInfo, OracleValue,
 };
 use either::Either;
 use futures_core::future::BoxFuture;
 use futures_core::stream::BoxStream;
-use futures_util::{TryFutureExt, TryStreamExt, pin_mut};
+use futures_util::{TryFutureExt, TryStreamExt, future, pin_mut};
+use oracle::sql_type::{FromSql, IntervalYM, OracleType, RefCursor, Timestamp};
 use rbdc::Error;
 use rbdc::try_stream;
 use std::sync::Arc;
 
 use super::OracleConnection;
 
-type StepResult = Result<Either<OracleQueryResult, OracleRow>, Error>;
+pub(crate) type StepResult = Result<Either<OracleQueryResult, OracleRow>, Error>;
 
 pub(crate) fn prepare(conn: &mut ConnectionState, query: &str) -> Result<OracleStatement, Error> {
     let statement = conn.statements.get(query, true)?;
@@ -22,7 +30,7 @@ pub(crate) fn prepare(conn: &mut ConnectionState, query: &str) -> Result<OracleS
     let mut columns = None;
 
     while let Some(prepared) = statement.prepare_next(&mut conn.handle)? {
-        parameters += count_bind_parameters(&conn.handle, prepared.handle.sql())?;
+        parameters += prepared.handle.statement().bind_count();
         if !prepared.columns.is_empty() && columns.is_none() {
             columns = Some(Arc::clone(prepared.columns));
         }
@@ -35,62 +43,197 @@ pub(crate) fn prepare(conn: &mut ConnectionState, query: &str) -> Result<OracleS
     })
 }
 
-pub(crate) fn run_prepared(
-    handle: &mut crate::connection::ConnectionHandle,
-    query: &str,
-    columns: &mut Arc<Vec<OracleColumn>>,
-    bind: impl FnOnce(&mut oracle::Statement) -> Result<usize, Error>,
-) -> Result<(Vec<StepResult>, usize), Error> {
-    let mut statement = build_statement(handle, query)?;
-    let args_used_now = bind(&mut statement)?;
-    let mut results = execute_statement(&mut statement, columns)?;
-    results.push(Ok(Either::Left(build_query_result(&statement)?)));
+pub(crate) enum Execution<'s> {
+    Rows(QueryRows<'s>),
+    /// A finished statement, followed by the ref cursors and implicit result
+    /// sets a PL/SQL block handed back, in that order.
+    Done(OracleQueryResult, Vec<QueryRows<'s>>),
+}
 
-    Ok((results, args_used_now))
+/// Rows of an executed query, fetched from the live result set one at a time.
+pub(crate) struct QueryRows<'s> {
+    rows: RowSource<'s>,
+    columns: Arc<Vec<OracleColumn>>,
+    options: ValueOptions,
+    fetched: u64,
+    finished: bool,
 }
 
-fn count_bind_parameters(
-    handle: &crate::connection::ConnectionHandle,
-    query: &str,
-) -> Result<usize, Error> {
-    let statement = build_statement(handle, query)?;
-    Ok(statement.bind_count())
+enum RowSource<'s> {
+    Statement(oracle::ResultSet<'s, oracle::Row>),
+    // The fetch position lives in the cursor itself, so a fresh result set per
+    // row keeps streaming without borrowing from the cursor.
+    Cursor(RefCursor),
 }
 
-fn build_statement(
-    handle: &crate::connection::ConnectionHandle,
-    query: &str,
-) -> Result<oracle::Statement, Error> {
-    let arc = handle.as_arc();
-    arc.statement(query).build().map_err(to_error)
-}
-
-fn execute_statement(
-    statement: &mut oracle::Statement,
-    columns: &mut Arc<Vec<OracleColumn>>,
-) -> Result<Vec<StepResult>, Error> {
-    match statement.query(&[]) {
-        Ok(rows) => collect_query_rows(rows, columns),
-        Err(_) => {
-            statement.execute(&[]).map_err(to_error)?;
-            Ok(Vec::with_capacity(1))
+impl QueryRows<'_> {
+    fn from_cursor(mut cursor: RefCursor, options: ValueOptions) -> Result<Self, Error> {
+        let mut columns = Arc::default();
+        cache_columns(
+            &mut columns,
+            cursor.query().map_err(to_error)?.column_info(),
+        );
+        Ok(QueryRows {
+            rows: RowSource::Cursor(cursor),
+            columns,
+            options,
+            fetched: 0,
+            finished: false,
+        })
+    }
+
+    fn next_row(&mut self) -> Option<oracle::Result<oracle::Row>> {
+        match &mut self.rows {
+            RowSource::Statement(rows) => rows.next(),
+            RowSource::Cursor(cursor) => match cursor.query() {
+                Ok(mut rows) => rows.next(),
+                Err(e) => Some(Err(e)),
+            },
+        }
+    }
+}
+
+impl Iterator for QueryRows<'_> {
+    type Item = StepResult;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        match self.next_row() {
+            Some(row) => {
+                self.fetched += 1;
+                let row = row
+                    .map_err(to_error)
+                    .and_then(|row| collect_row(&row, &self.columns, self.options));
+                self.finished = row.is_err();
+                Some(row.map(Either::Right))
+            }
+            None => {
+                self.finished = true;
+                // Cursors belong to the PL/SQL block whose result was already
+                // reported, so only a query of its own gets a summary.
+                match self.rows {
+                    RowSource::Statement(_) => Some(Ok(Either::Left(OracleQueryResult {
+                        rows_affected: self.fetched,
+                        ..Default::default()
+                    }))),
+                    RowSource::Cursor(_) => None,
+                }
+            }
+        }
+    }
+}
+
+/// Declared types of a statement's OUT binds, indexed by bind position.
+pub(crate) type OutTypes = Vec<Option<OracleType>>;
+
+pub(crate) fn run_prepared<'s>(
+    prepared: PreparedStatement<'s>,
+    options: ValueOptions,
+    bind: impl FnOnce(&mut oracle::Statement) -> Result<(usize, OutTypes), Error>,
+) -> Result<(Execution<'s>, usize), Error> {
+    let PreparedStatement { handle, columns } = prepared;
+    let statement = handle.statement_mut();
+    let (args_used_now, out_types) = bind(statement)?;
+    let execution = execute_statement(statement, columns, &out_types, options)?;
+
+    Ok((execution, args_used_now))
+}
+
+fn execute_statement<'s>(
+    statement: &'s mut oracle::Statement,
+    columns: &'s mut Arc<Vec<OracleColumn>>,
+    out_types: &[Option<OracleType>],
+    options: ValueOptions,
+) -> Result<Execution<'s>, Error> {
+    // The statement type comes from the client-side parse, so each statement is
+    // executed exactly once and its own ORA error reaches the caller.
+    if statement.is_query() {
+        // The result set borrows the cached statement so rows can be pulled
+        // lazily by the caller instead of being drained here.
+        let rows = statement.query(&[]).map_err(to_error)?;
+        cache_columns(columns, rows.column_info());
+        return Ok(Execution::Rows(QueryRows {
+            rows: RowSource::Statement(rows),
+            columns: Arc::clone(columns),
+            options,
+            fetched: 0,
+            finished: false,
+        }));
+    }
+
+    statement.execute(&[]).map_err(to_error)?;
+    let mut cursors = Vec::new();
+    let result = build_query_result(statement, out_types, options, &mut cursors)?;
+    if statement.is_plsql() {
+        loop {
+            match statement.implicit_result() {
+                Ok(Some(cursor)) => cursors.push(QueryRows::from_cursor(cursor, options)?),
+                Ok(None) => break,
+                // DPI-1050: clients older than 12.1 cannot return implicit
+                // results, which simply means there are none.
+                Err(e) if e.dpi_code() == Some(1050) => break,
+                Err(e) => return Err(to_error(e)),
+            }
         }
     }
+
+    Ok(Execution::Done(result, cursors))
 }
 
-fn collect_query_rows(
-    rows: oracle::ResultSet<'_, oracle::Row>,
-    columns: &mut Arc<Vec<OracleColumn>>,
-) -> Result<Vec<StepResult>, Error> {
-    cache_columns(columns, rows.column_info());
+/// Runs `query` once per row using Oracle array binding.
+///
+/// With `batch_errors` set, failing rows are reported in the result instead of
+/// aborting the whole batch.
+pub(crate) fn execute_batch(
+    conn: &mut ConnectionState,
+    query: &str,
+    rows: Vec<OracleArguments>,
+    batch_errors: bool,
+) -> Result<OracleQueryResult, Error> {
+    if rows.is_empty() {
+        return Ok(OracleQueryResult::default());
+    }
+
+    let commit = conn.autocommit && conn.transaction_depth == 0;
+    conn.handle.set_commit_on_success(commit);
+    let result = run_batch(conn, query, &rows, batch_errors);
+    conn.handle.set_commit_on_success(false);
+    result
+}
 
-    let mut results = Vec::with_capacity(columns.len());
-    for row in rows {
-        let row = row.map_err(to_error)?;
-        results.push(collect_row(&row, columns).map(Either::Right));
+fn run_batch(
+    conn: &ConnectionState,
+    query: &str,
+    rows: &[OracleArguments],
+    batch_errors: bool,
+) -> Result<OracleQueryResult, Error> {
+    let connection = conn.handle.connection();
+    let mut builder = connection.batch(query, rows.len());
+    builder.with_row_counts();
+    if batch_errors {
+        builder.with_batch_errors();
     }
+    let mut batch = builder.build().map_err(to_error)?;
+    OracleArguments::bind_batch(rows, connection, &mut batch, conn.value_options)?;
+
+    let errors = match batch.execute() {
+        Ok(()) => Vec::new(),
+        Err(e) => match e.batch_errors() {
+            Some(errors) => errors.iter().map(OracleBatchError::from).collect(),
+            None => return Err(to_error(e)),
+        },
+    };
 
-    Ok(results)
+    let row_counts = batch.row_counts().map_err(to_error)?;
+    Ok(OracleQueryResult {
+        rows_affected: row_counts.iter().sum(),
+        row_counts,
+        batch_errors: errors,
+        ..Default::default()
+    })
 }
 
 fn cache_columns(columns: &mut Arc<Vec<OracleColumn>>, column_info: &[oracle::ColumnInfo]) {
@@ -110,46 +253,214 @@ fn cache_columns(columns: &mut Arc<Vec<OracleColumn>>, column_info: &[oracle::Co
     *columns = Arc::new(cached);
 }
 
-fn collect_row(row: &oracle::Row, columns: &Arc<Vec<OracleColumn>>) -> Result<OracleRow, Error> {
+fn collect_row(
+    row: &oracle::Row,
+    columns: &Arc<Vec<OracleColumn>>,
+    options: ValueOptions,
+) -> Result<OracleRow, Error> {
     let sql_values = row.sql_values();
     let mut values = Vec::with_capacity(sql_values.len());
-    for value in sql_values.iter() {
-        values.push(collect_value(value)?);
+    for (value, column) in sql_values.iter().zip(columns.iter()) {
+        values.push(collect_value(value, column, options)?);
     }
 
     Ok(OracleRow::new(Arc::clone(columns), values))
 }
 
-fn build_query_result(statement: &oracle::Statement) -> Result<OracleQueryResult, Error> {
+fn build_query_result<'s>(
+    statement: &oracle::Statement,
+    out_types: &[Option<OracleType>],
+    options: ValueOptions,
+    cursors: &mut Vec<QueryRows<'s>>,
+) -> Result<OracleQueryResult, Error> {
+    // Only PL/SQL blocks and `RETURNING INTO` clauses can hand values back
+    // through their binds; for plain DML and DDL they are just the inputs.
+    if statement.is_returning() {
+        return Ok(OracleQueryResult {
+            rows_affected: statement.row_count().map_err(to_error)?,
+            returned_values: collect_returned_values(statement, out_types, options)?,
+            ..Default::default()
+        });
+    }
+
+    let out_values = if statement.is_plsql() {
+        collect_out_values(statement, out_types, options, cursors)?
+    } else {
+        Vec::new()
+    };
+
     Ok(OracleQueryResult {
         rows_affected: statement.row_count().map_err(to_error)?,
-        out_values: collect_out_values(statement),
+        out_values,
+        ..Default::default()
     })
 }
 
-fn collect_value(value: &oracle::SqlValue) -> Result<OracleValue, Error> {
-    let oracle_type = value.oracle_type().map_err(to_error)?.clone();
+/// Reads the `RETURNING INTO` binds back as one row of values per affected
+/// row.
+fn collect_returned_values(
+    statement: &oracle::Statement,
+    out_types: &[Option<OracleType>],
+    options: ValueOptions,
+) -> Result<Vec<Vec<rbs::Value>>, Error> {
+    let mut rows: Vec<Vec<rbs::Value>> = Vec::new();
+
+    for (position, oracle_type) in out_types.iter().enumerate() {
+        let Some(oracle_type) = oracle_type else {
+            continue;
+        };
+        let values = BindSource::Returned(statement, position + 1)
+            .values(oracle_type)?
+            .into_iter()
+            .map(|fetched| decode_bind_value(oracle_type, fetched, options))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        rows.resize_with(rows.len().max(values.len()), Vec::new);
+        for (row, value) in rows.iter_mut().zip(values) {
+            row.push(value);
+        }
+    }
+
+    Ok(rows)
+}
+
+/// A non-null fetched value, in the form [`OracleValue`] carries it.
+enum Fetched {
+    Text(String),
+    Binary(Vec<u8>),
+    Float(f64),
+}
+
+fn fetched_value(
+    fetched: Option<Fetched>,
+    oracle_type: OracleType,
+    is_null: bool,
+    options: ValueOptions,
+) -> OracleValue {
+    let type_info = OracleTypeInfo::from_oracle_type(oracle_type);
+    let mut value = OracleValue::new(None, None, type_info, is_null);
+    match fetched {
+        Some(Fetched::Text(text)) => value.text = Some(text.into()),
+        Some(Fetched::Binary(binary)) => value.binary = Some(binary.into()),
+        Some(Fetched::Float(float)) => value = value.with_float(float),
+        None => {}
+    }
+    value.options = options;
+    value
+}
+
+/// Where bind values are read back from: the single value of an OUT bind, or
+/// one value per affected row of a `RETURNING INTO` bind.
+enum BindSource<'a> {
+    Out(&'a oracle::Statement, usize),
+    Returned(&'a oracle::Statement, usize),
+}
+
+impl BindSource<'_> {
+    fn read<T: FromSql>(
+        &self,
+        fetched: impl Fn(T) -> Fetched,
+    ) -> Result<Vec<Option<Fetched>>, Error> {
+        let values: Vec<Option<T>> = match *self {
+            BindSource::Out(statement, index) => {
+                statement.bind_value(index).map(|value| vec![value])
+            }
+            BindSource::Returned(statement, index) => statement.returned_values(index),
+        }
+        .map_err(to_error)?;
+        Ok(values
+            .into_iter()
+            .map(|value| value.map(&fetched))
+            .collect())
+    }
+
+    /// The bind values read the same way as a column of `oracle_type`.
+    fn values(&self, oracle_type: &OracleType) -> Result<Vec<Option<Fetched>>, Error> {
+        match oracle_type {
+            _ if is_binary(oracle_type) => self.read(Fetched::Binary),
+            _ if is_datetime(oracle_type) => {
+                self.read(|value: Timestamp| Fetched::Text(timestamp_text(&value, oracle_type)))
+            }
+            OracleType::BinaryFloat => self.read(|value: f32| Fetched::Float(value.into())),
+            OracleType::BinaryDouble => self.read(Fetched::Float),
+            OracleType::IntervalDS(..) => {
+                self.read(|value: chrono::Duration| Fetched::Text(interval_ds_text(&value)))
+            }
+            OracleType::IntervalYM(_) => self.read(|value: IntervalYM| {
+                Fetched::Text(interval_ym_text(interval_ym_months(&value)))
+            }),
+            _ => self.read(Fetched::Text),
+        }
+    }
+}
+
+fn collect_value(
+    value: &oracle::SqlValue,
+    column: &OracleColumn,
+    options: ValueOptions,
+) -> Result<OracleValue, Error> {
+    // Small `NUMBER(p, 0)` columns are fetched as `Int64`; decode by the
+    // declared type so the precision still picks the Rust integer.
+    let oracle_type = match column.type_info.oracle_type() {
+        Some(oracle_type) => oracle_type.clone(),
+        None => value.oracle_type().map_err(to_error)?.clone(),
+    };
     let is_null = value.is_null().map_err(to_error)?;
-    let (text, binary) = if is_null {
-        (None, None)
-    } else if oracle_type == oracle::sql_type::OracleType::BLOB {
-        (None, value.get::<Vec<u8>>().ok())
+    let fetched = if is_null {
+        None
+    } else if is_binary(&oracle_type) {
+        value.get::<Vec<u8>>().ok().map(Fetched::Binary)
+    } else if is_datetime(&oracle_type) {
+        let timestamp = value.get::<Timestamp>().map_err(to_error)?;
+        Some(Fetched::Text(timestamp_text(&timestamp, &oracle_type)))
+    } else if oracle_type == OracleType::BinaryFloat {
+        // Fetched natively, so NaN and the infinities survive as they are.
+        let float = value.get::<f32>().map_err(to_error)?;
+        Some(Fetched::Float(float.into()))
+    } else if oracle_type == OracleType::BinaryDouble {
+        Some(Fetched::Float(value.get::<f64>().map_err(to_error)?))
+    } else if let OracleType::IntervalDS(..) = oracle_type {
+        let interval = value.get::<chrono::Duration>().map_err(to_error)?;
+        Some(Fetched::Text(interval_ds_text(&interval)))
+    } else if let OracleType::IntervalYM(_) = oracle_type {
+        let interval = value.get::<IntervalYM>().map_err(to_error)?;
+        Some(Fetched::Text(interval_ym_text(interval_ym_months(
+            &interval,
+        ))))
     } else {
-        (value.get::<String>().ok(), None)
+        value.get::<String>().ok().map(Fetched::Text)
     };
 
-    Ok(OracleValue::new(
-        text,
-        binary,
-        OracleTypeInfo::from_oracle_type(oracle_type),
-        is_null,
-    ))
+    Ok(fetched_value(fetched, oracle_type, is_null, options))
 }
 
-fn collect_out_values(statement: &oracle::Statement) -> Vec<rbs::Value> {
+fn collect_out_values<'s>(
+    statement: &oracle::Statement,
+    out_types: &[Option<OracleType>],
+    options: ValueOptions,
+    cursors: &mut Vec<QueryRows<'s>>,
+) -> Result<Vec<rbs::Value>, Error> {
     let mut out_values = Vec::with_capacity(statement.bind_count());
 
     for index in 1..=statement.bind_count() {
+        // Ref cursors are streamed as rows after the statement result, so
+        // their slot in the OUT values stays empty.
+        if let Some(Some(OracleType::RefCursor)) = out_types.get(index - 1) {
+            let cursor: Option<RefCursor> = statement.bind_value(index).map_err(to_error)?;
+            if let Some(cursor) = cursor {
+                cursors.push(QueryRows::from_cursor(cursor, options)?);
+            }
+            out_values.push(rbs::Value::Null);
+            continue;
+        }
+
+        // Binds declared as OUT / IN OUT are decoded like a column of their
+        // type; the rest keep being reported as text.
+        if let Some(Some(oracle_type)) = out_types.get(index - 1) {
+            out_values.push(collect_bind_value(statement, index, oracle_type, options)?);
+            continue;
+        }
+
         let value: Result<String, _> = statement.bind_value(index);
         match value {
             Ok(value) => out_values.push(rbs::Value::String(value)),
@@ -157,11 +468,34 @@ fn collect_out_values(statement: &oracle::Statement) -> Vec<rbs::Value> {
         }
     }
 
-    out_values
+    Ok(out_values)
+}
+
+fn collect_bind_value(
+    statement: &oracle::Statement,
+    index: usize,
+    oracle_type: &OracleType,
+    options: ValueOptions,
+) -> Result<rbs::Value, Error> {
+    let fetched = BindSource::Out(statement, index)
+        .values(oracle_type)?
+        .pop()
+        .flatten();
+    decode_bind_value(oracle_type, fetched, options)
+}
+
+fn decode_bind_value(
+    oracle_type: &OracleType,
+    fetched: Option<Fetched>,
+    options: ValueOptions,
+) -> Result<rbs::Value, Error> {
+    let is_null = fetched.is_none();
+    let value = fetched_value(fetched, oracle_type.clone(), is_null, options);
+    rbs::Value::decode(value.as_ref())
 }
 
-fn to_error(error: impl std::fmt::Display) -> Error {
-    Error::from(error.to_string())
+fn to_error(error: oracle::Error) -> Error {
+    Error::from(OracleError::from(error))
 }
 
 impl OracleConnection {
@@ -208,6 +542,78 @@ impl OracleConnection {
         })
     }
 
+    /// Executes `sql` once for every row of `rows` in a single round trip,
+    /// returning the affected row count of each row in
+    /// [`OracleQueryResult::row_counts`].
+    pub fn exec_batch(
+        &mut self,
+        sql: &str,
+        rows: Vec<Vec<rbs::Value>>,
+    ) -> BoxFuture<'_, Result<OracleQueryResult, Error>> {
+        self.exec_batch_inner(sql, rows, false)
+    }
+
+    /// Like [`exec_batch`](Self::exec_batch), but keeps going past failing rows
+    /// and reports them in [`OracleQueryResult::batch_errors`].
+    pub fn exec_batch_with_errors(
+        &mut self,
+        sql: &str,
+        rows: Vec<Vec<rbs::Value>>,
+    ) -> BoxFuture<'_, Result<OracleQueryResult, Error>> {
+        self.exec_batch_inner(sql, rows, true)
+    }
+
+    fn exec_batch_inner(
+        &mut self,
+        sql: &str,
+        rows: Vec<Vec<rbs::Value>>,
+        batch_errors: bool,
+    ) -> BoxFuture<'_, Result<OracleQueryResult, Error>> {
+        let sql = crate::OracleDriver.pub_exchange(sql);
+        Box::pin(async move {
+            let mut arguments = Vec::with_capacity(rows.len());
+            for row in rows {
+                arguments.push(OracleArguments::from_args(row)?.into_static());
+            }
+            self.worker
+                .execute_batch(sql, arguments, batch_errors)
+                .await
+        })
+    }
+
+    /// Marks a savepoint inside the current transaction.
+    pub fn savepoint(&mut self, name: &str) -> BoxFuture<'_, Result<(), Error>> {
+        self.savepoint_command(format!("savepoint {name}"), name)
+    }
+
+    /// Undoes the work done since `name` without ending the transaction.
+    pub fn rollback_to_savepoint(&mut self, name: &str) -> BoxFuture<'_, Result<(), Error>> {
+        self.savepoint_command(format!("rollback to savepoint {name}"), name)
+    }
+
+    /// Oracle has no RELEASE SAVEPOINT, so this only checks `name`; the
+    /// savepoint is dropped when the transaction ends.
+    pub fn release_savepoint(&mut self, name: &str) -> BoxFuture<'_, Result<(), Error>> {
+        self.savepoint_command(format!("release savepoint {name}"), name)
+    }
+
+    fn savepoint_command(&mut self, sql: String, name: &str) -> BoxFuture<'_, Result<(), Error>> {
+        if !super::execute::is_savepoint_name(name) {
+            let error = Error::from(format!("invalid savepoint name: {name}"));
+            return Box::pin(future::err(error));
+        }
+        Box::pin(async move {
+            let stream = self.fetch_many(OracleQuery {
+                statement: Either::Left(sql),
+                arguments: vec![],
+                persistent: false,
+            });
+            pin_mut!(stream);
+            while stream.try_next().await?.is_some() {}
+            Ok(())
+        })
+    }
+
     pub fn prepare_with<'a>(
         &'a mut self,
         sql: &'a str,
@@ -1,23 +1,25 @@
 use oracle::Connection as RawOracleConnection;
-use std::sync::Arc;
 
 #[derive(Debug)]
-pub(crate) struct ConnectionHandle(Arc<RawOracleConnection>);
+pub(crate) struct ConnectionHandle(RawOracleConnection);
 
 unsafe impl Send for ConnectionHandle {}
 
 impl ConnectionHandle {
     pub(crate) fn new(connection: RawOracleConnection) -> Self {
-        Self(Arc::new(connection))
+        Self(connection)
     }
 
     #[inline]
     pub(crate) fn connection(&self) -> &RawOracleConnection {
-        self.0.as_ref()
+        &self.0
     }
 
+    /// Makes the next executions commit in the same round trip when they
+    /// succeed. The mode is read by OCI at execute time only, so it must be
+    /// switched off again before anything else runs.
     #[inline]
-    pub(crate) fn as_arc(&self) -> Arc<RawOracleConnection> {
-        Arc::clone(&self.0)
+    pub(crate) fn set_commit_on_success(&mut self, enabled: bool) {
+        self.0.set_autocommit(enabled);
     }
 }
@@ -7,10 +7,11 @@ use std::sync::atomic::Ordering;
 
 pub(crate) use handle::ConnectionHandle;
 
-use crate::OracleConnectOptions;
 use crate::connection::establish::EstablishParams;
 use crate::connection::worker::ConnectionWorker;
 use crate::statement::VirtualStatement;
+use crate::value::ValueOptions;
+use crate::{OracleConnectOptions, OracleIsolationLevel};
 
 mod establish;
 mod execute;
@@ -29,7 +30,16 @@ unsafe impl Sync for OracleConnection {}
 
 pub struct ConnectionState {
     pub(crate) handle: ConnectionHandle,
-    pub(crate) transaction_active: bool,
+    /// Number of open `begin`s; nested levels are backed by savepoints.
+    pub(crate) transaction_depth: usize,
+    /// Commit statements run outside a transaction; see
+    /// [`OracleConnectOptions::autocommit`].
+    pub(crate) autocommit: bool,
+    /// Mode a plain `begin` starts transactions in.
+    pub(crate) default_isolation: Option<OracleIsolationLevel>,
+    /// Mode of the open transaction, if one was set when it began.
+    pub(crate) isolation: Option<OracleIsolationLevel>,
+    pub(crate) value_options: ValueOptions,
     pub(crate) statements: Statements,
 }
 
@@ -67,6 +77,13 @@ impl OracleConnection {
         Box::pin(self.worker.ping())
     }
 
+    /// Returns `false` once the session has been lost (e.g. ORA-03113 or
+    /// DPI-1080) or its worker has stopped, such as after a failed ping, so
+    /// pools can discard the connection instead of reusing it.
+    pub fn is_valid(&self) -> bool {
+        self.worker.ensure_valid().is_ok()
+    }
+
     pub fn cached_statements_size(&self) -> usize {
         self.worker
             .shared
@@ -1,11 +1,13 @@
-use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
 use std::thread;
 
 use crate::connection::ConnectionState;
 use crate::connection::establish::EstablishParams;
 use crate::connection::execute;
-use crate::{OracleArguments, OracleQueryResult, OracleRow, OracleStatement};
+use crate::{
+    OracleArguments, OracleError, OracleErrorKind, OracleQueryResult, OracleRow, OracleStatement,
+};
 use crossfire::{AsyncTx, spsc};
 use either::Either;
 use futures_channel::oneshot;
@@ -20,6 +22,30 @@ pub(crate) struct ConnectionWorker {
 pub(crate) struct WorkerSharedState {
     pub(crate) cached_statements_size: AtomicUsize,
     pub(crate) conn: Mutex<ConnectionState>,
+    /// The first connection-lost error seen, or why the worker stopped; once
+    /// set the session is unusable.
+    pub(crate) broken: OnceLock<Error>,
+}
+
+impl WorkerSharedState {
+    fn check_fatal<T>(&self, result: &Result<T, Error>) {
+        if let Err(e) = result
+            && OracleError::from(e).kind() == OracleErrorKind::ConnectionLost
+        {
+            let _ = self.broken.set(e.clone());
+        }
+    }
+}
+
+/// Marks the session broken when the worker thread exits for any reason,
+/// including a panic, so `is_valid` stops reporting a connection whose
+/// commands can no longer run.
+struct ExitGuard(Arc<WorkerSharedState>);
+
+impl Drop for ExitGuard {
+    fn drop(&mut self) {
+        let _ = self.0.broken.set(Error::from("WorkerCrashed"));
+    }
 }
 
 pub enum Command {
@@ -35,6 +61,12 @@ pub enum Command {
             crossfire::spsc::Array<Result<Either<OracleQueryResult, OracleRow>, Error>>,
         >,
     },
+    ExecuteBatch {
+        query: Box<str>,
+        arguments: Vec<OracleArguments>,
+        batch_errors: bool,
+        tx: oneshot::Sender<Result<OracleQueryResult, Error>>,
+    },
     ClearCache {
         tx: oneshot::Sender<()>,
     },
@@ -67,7 +99,9 @@ impl ConnectionWorker {
                 let shared = Arc::new(WorkerSharedState {
                     cached_statements_size: AtomicUsize::new(0),
                     conn: Mutex::new(conn),
+                    broken: OnceLock::new(),
                 });
+                let _exit = ExitGuard(Arc::clone(&shared));
                 let mut conn = shared.conn.lock().unwrap();
 
                 if establish_tx
@@ -88,14 +122,14 @@ impl ConnectionWorker {
 
                     match cmd {
                         Command::Prepare { query, tx } => {
-                            tx.send(prepare(&mut conn, &query).map(|prepared| {
+                            let result = prepare(&mut conn, &query).inspect(|_| {
                                 update_cached_statements_size(
                                     &conn,
                                     &shared.cached_statements_size,
                                 );
-                                prepared
-                            }))
-                            .ok();
+                            });
+                            shared.check_fatal(&result);
+                            tx.send(result).ok();
                         }
                         Command::Execute {
                             query,
@@ -107,12 +141,15 @@ impl ConnectionWorker {
                             {
                                 Ok(iter) => iter,
                                 Err(e) => {
-                                    tx.send(Err(e)).ok();
+                                    let result = Err(e);
+                                    shared.check_fatal(&result);
+                                    tx.send(result).ok();
                                     continue;
                                 }
                             };
 
                             for res in iter {
+                                shared.check_fatal(&res);
                                 if tx.send(res).is_err() {
                                     break;
                                 }
@@ -120,6 +157,16 @@ impl ConnectionWorker {
 
                             update_cached_statements_size(&conn, &shared.cached_statements_size);
                         }
+                        Command::ExecuteBatch {
+                            query,
+                            arguments,
+                            batch_errors,
+                            tx,
+                        } => {
+                            let result = execute_batch(&mut conn, &query, arguments, batch_errors);
+                            shared.check_fatal(&result);
+                            tx.send(result).ok();
+                        }
                         Command::ClearCache { tx } => {
                             conn.statements.clear();
                             update_cached_statements_size(&conn, &shared.cached_statements_size);
@@ -130,7 +177,10 @@ impl ConnectionWorker {
                                 .handle
                                 .connection()
                                 .ping()
-                                .map_err(|e| Error::from(e.to_string()));
+                                .map_err(|e| Error::from(OracleError::from(e)));
+                            if let Err(e) = &result {
+                                let _ = shared.broken.set(e.clone());
+                            }
                             let should_stop = result.is_err();
                             tx.send(result).ok();
                             if should_stop {
@@ -138,7 +188,9 @@ impl ConnectionWorker {
                             }
                         }
                         Command::Shutdown { tx } => {
-                            let _ = conn.handle.connection().commit();
+                            if conn.autocommit {
+                                let _ = conn.handle.connection().commit();
+                            }
                             let _ = conn.handle.connection().close();
                             drop(conn);
                             drop(shared);
@@ -155,7 +207,16 @@ impl ConnectionWorker {
             .map_err(|_| Error::from("WorkerCrashed"))?
     }
 
+    /// Fails fast with the recorded error once the session has been lost.
+    pub(crate) fn ensure_valid(&self) -> Result<(), Error> {
+        match self.shared.broken.get() {
+            Some(e) => Err(e.clone()),
+            None => Ok(()),
+        }
+    }
+
     pub(crate) async fn prepare(&mut self, query: &str) -> Result<OracleStatement, Error> {
+        self.ensure_valid()?;
         self.oneshot_cmd(|tx| Command::Prepare {
             query: query.into(),
             tx,
@@ -175,6 +236,7 @@ impl ConnectionWorker {
         >,
         Error,
     > {
+        self.ensure_valid()?;
         let (tx, rx) = spsc::bounded_blocking_async(chan_size);
 
         self.command_tx
@@ -190,7 +252,24 @@ impl ConnectionWorker {
         Ok(rx)
     }
 
+    pub(crate) async fn execute_batch(
+        &mut self,
+        query: String,
+        arguments: Vec<OracleArguments>,
+        batch_errors: bool,
+    ) -> Result<OracleQueryResult, Error> {
+        self.ensure_valid()?;
+        self.oneshot_cmd(|tx| Command::ExecuteBatch {
+            query: query.into(),
+            arguments,
+            batch_errors,
+            tx,
+        })
+        .await?
+    }
+
     pub(crate) async fn ping(&mut self) -> Result<(), Error> {
+        self.ensure_valid()?;
         self.oneshot_cmd(|tx| Command::Ping { tx }).await?
     }
 
@@ -231,3 +310,12 @@ fn update_cached_statements_size(conn: &ConnectionState, size: &AtomicUsize) {
 fn prepare(conn: &mut ConnectionState, query: &str) -> Result<OracleStatement, Error> {
     super::executor::prepare(conn, query)
 }
+
+fn execute_batch(
+    conn: &mut ConnectionState,
+    query: &str,
+    arguments: Vec<OracleArguments>,
+    batch_errors: bool,
+) -> Result<OracleQueryResult, Error> {
+    super::executor::execute_batch(conn, query, arguments, batch_errors)
+}
@@ -1,26 +1,103 @@
 use std::error::Error as StdError;
 use std::fmt::{self, Display, Formatter};
 
+const OFFSET_PREFIX: &str = "offset: ";
+const FN_NAME_PREFIX: &str = "fn: ";
+
+/// Broad category of an [`OracleError`], derived from its ORA/DPI code.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum OracleErrorKind {
+    /// ORA-00001
+    UniqueViolation,
+    /// ORA-02291, ORA-02292
+    ForeignKeyViolation,
+    /// ORA-01400, ORA-01407
+    NotNullViolation,
+    /// ORA-02290
+    CheckViolation,
+    /// ORA-00060
+    Deadlock,
+    /// ORA-00054, ORA-01013, ORA-30006, DPI-1067
+    Timeout,
+    /// The session or network connection is gone, e.g. ORA-03113 or DPI-1080.
+    ConnectionLost,
+    /// Any other database error.
+    Database,
+    /// An error raised by the driver itself, without an ORA/DPI code.
+    Driver,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct OracleError {
     message: String,
+    code: Option<i32>,
+    offset: u32,
+    fn_name: Option<String>,
+    kind: OracleErrorKind,
 }
 
 impl OracleError {
     pub fn new(message: impl Into<String>) -> Self {
+        let message = message.into();
+        let (code, kind) = classify(&message);
         Self {
-            message: message.into(),
+            message,
+            code,
+            offset: 0,
+            fn_name: None,
+            kind,
         }
     }
 
+    /// Sets the parse offset reported by the server.
+    pub fn with_offset(mut self, offset: u32) -> Self {
+        self.offset = offset;
+        self
+    }
+
     pub fn message(&self) -> &str {
         &self.message
     }
+
+    /// The ORA error code, e.g. `1` for ORA-00001.
+    pub fn code(&self) -> Option<i32> {
+        self.code
+    }
+
+    /// Byte offset into the SQL text where parsing failed, or `0`.
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// The ODPI-C function that raised the error, e.g. `dpiStmt_execute`.
+    pub fn fn_name(&self) -> Option<&str> {
+        self.fn_name.as_deref()
+    }
+
+    pub fn kind(&self) -> OracleErrorKind {
+        self.kind
+    }
 }
 
+/// Appends the offset and function name, when known, as
+/// `message (offset: 14, fn: dpiStmt_execute)`, so they survive the trip
+/// through `rbdc::Error`.
 impl Display for OracleError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        f.pad(&self.message)
+        let mut details = Vec::with_capacity(2);
+        if self.offset != 0 {
+            details.push(format!("{OFFSET_PREFIX}{}", self.offset));
+        }
+        if let Some(fn_name) = &self.fn_name {
+            details.push(format!("{FN_NAME_PREFIX}{fn_name}"));
+        }
+
+        if details.is_empty() {
+            f.pad(&self.message)
+        } else {
+            write!(f, "{} ({})", self.message, details.join(", "))
+        }
     }
 }
 
@@ -28,7 +105,18 @@ impl StdError for OracleError {}
 
 impl From<oracle::Error> for OracleError {
     fn from(value: oracle::Error) -> Self {
-        Self::new(value.to_string())
+        let Some(db_error) = value.db_error() else {
+            return Self::new(value.to_string());
+        };
+
+        let code = value.oci_code();
+        Self {
+            message: db_error.message().to_owned(),
+            code,
+            offset: db_error.offset(),
+            fn_name: Some(db_error.fn_name().to_owned()),
+            kind: kind_of(code, value.dpi_code()),
+        }
     }
 }
 
@@ -37,3 +125,110 @@ impl From<OracleError> for rbdc::Error {
         Self::from(value.to_string())
     }
 }
+
+/// Recovers the code, offset, function name and kind of an error returned
+/// through `rbdc`.
+impl From<&rbdc::Error> for OracleError {
+    fn from(value: &rbdc::Error) -> Self {
+        let rbdc::Error::E(text) = value;
+        parse_details(text).unwrap_or_else(|| Self::new(text.as_str()))
+    }
+}
+
+/// Splits the details written by `Display` back off the message. Messages
+/// that merely end in parentheses, such as a constraint name, are left alone.
+fn parse_details(text: &str) -> Option<OracleError> {
+    let (message, details) = text.strip_suffix(')')?.rsplit_once(" (")?;
+    let mut error = OracleError::new(message);
+    for detail in details.split(", ") {
+        if let Some(offset) = detail.strip_prefix(OFFSET_PREFIX) {
+            error.offset = offset.parse().ok()?;
+        } else if let Some(fn_name) = detail.strip_prefix(FN_NAME_PREFIX) {
+            error.fn_name = Some(fn_name.to_owned());
+        } else {
+            return None;
+        }
+    }
+    Some(error)
+}
+
+fn classify(message: &str) -> (Option<i32>, OracleErrorKind) {
+    let code = parse_code(message, "ORA-");
+    let dpi_code = code
+        .is_none()
+        .then(|| parse_code(message, "DPI-"))
+        .flatten();
+    (code, kind_of(code, dpi_code))
+}
+
+fn kind_of(code: Option<i32>, dpi_code: Option<i32>) -> OracleErrorKind {
+    if let Some(code) = code {
+        return match code {
+            1 => OracleErrorKind::UniqueViolation,
+            2291 | 2292 => OracleErrorKind::ForeignKeyViolation,
+            1400 | 1407 => OracleErrorKind::NotNullViolation,
+            2290 => OracleErrorKind::CheckViolation,
+            60 => OracleErrorKind::Deadlock,
+            54 | 1013 | 30006 => OracleErrorKind::Timeout,
+            28 | 1012 | 2396 | 3113 | 3114 | 3135 | 12537 | 12547 => {
+                OracleErrorKind::ConnectionLost
+            }
+            _ => OracleErrorKind::Database,
+        };
+    }
+
+    match dpi_code {
+        Some(1010 | 1080) => OracleErrorKind::ConnectionLost,
+        Some(1067) => OracleErrorKind::Timeout,
+        Some(_) => OracleErrorKind::Database,
+        None => OracleErrorKind::Driver,
+    }
+}
+
+fn parse_code(message: &str, prefix: &str) -> Option<i32> {
+    let digits = message.trim_start().strip_prefix(prefix)?;
+    let end = digits
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(digits.len());
+    digits[..end].parse().ok()
+}
+
+/// A row rejected by a batch executed with batch errors enabled.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct OracleBatchError {
+    offset: usize,
+    code: i32,
+    message: String,
+}
+
+impl OracleBatchError {
+    /// Zero-based index of the rejected row in the batch.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The ORA error code, e.g. `1` for ORA-00001.
+    pub fn code(&self) -> i32 {
+        self.code
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for OracleBatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "row {}: {}", self.offset, self.message)
+    }
+}
+
+impl From<&oracle::DbError> for OracleBatchError {
+    fn from(value: &oracle::DbError) -> Self {
+        Self {
+            offset: value.offset() as usize,
+            code: value.code(),
+            message: value.message().to_owned(),
+        }
+    }
+}
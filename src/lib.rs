@@ -6,8 +6,10 @@ pub use connection::OracleConnection;
 pub use database::Oracle;
 pub use driver::OracleDriver;
 pub use driver::OracleDriver as Driver;
-pub use error::OracleError;
-pub use options::OracleConnectOptions;
+pub use error::{OracleBatchError, OracleError, OracleErrorKind};
+pub use options::{
+    OracleBoolEncoding, OracleConnectOptions, OracleIsolationLevel, OracleUuidEncoding,
+};
 pub use query::OracleQuery;
 pub use query_result::OracleQueryResult;
 pub use row::OracleRow;
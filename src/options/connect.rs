@@ -32,13 +32,11 @@ impl Connection for OracleConnection {
                 let arguments = OracleArguments::from_args(params)?;
                 self.worker
                     .execute(sql, Some(arguments.into_static()), row_channel_size, true)
-                    .await
-                    .map_err(|_| Error::from("WorkerCrashed"))?
+                    .await?
             } else {
                 self.worker
                     .execute(sql, None, row_channel_size, false)
-                    .await
-                    .map_err(|_| Error::from("WorkerCrashed"))?
+                    .await?
             };
 
             let stream = try_stream! {
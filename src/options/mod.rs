@@ -5,6 +5,8 @@ use futures_core::future::BoxFuture;
 use rbdc::Error;
 use rbdc::db::{ConnectOptions, Connection};
 use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
 
 /// Connection options for Oracle.
 ///
@@ -23,6 +25,140 @@ pub struct OracleConnectOptions {
     pub row_channel_size: usize,
     #[serde(default = "OracleConnectOptions::default_command_channel_size")]
     pub command_channel_size: usize,
+    /// Commit each statement run outside a transaction as part of its execute
+    /// call. When disabled, work only ends up committed by an explicit
+    /// `commit`.
+    #[serde(default = "OracleConnectOptions::default_autocommit")]
+    pub autocommit: bool,
+    /// Mode applied by a plain `begin`; `None` keeps the server default.
+    #[serde(default)]
+    pub isolation_level: Option<OracleIsolationLevel>,
+    /// How `bool` values are bound when the server or client predates the
+    /// native SQL `BOOLEAN` of Oracle 23ai, which is used whenever available
+    /// unless `bool_encoding` is set.
+    #[serde(default)]
+    pub bool_fallback: OracleBoolEncoding,
+    /// Convention every `bool` is stored in, regardless of server version.
+    /// Binds use it, and `NUMBER(1)` or `CHAR(1)` columns holding its two
+    /// literals decode back to `bool`. `None` keeps the automatic choice.
+    #[serde(default)]
+    pub bool_encoding: Option<OracleBoolEncoding>,
+    /// Column layout UUIDs are stored in. Binds use it, and columns of that
+    /// layout holding a valid UUID decode to `Ext("Uuid")`. `None` binds the
+    /// UUID text as given and leaves columns as plain bytes or text.
+    #[serde(default)]
+    pub uuid_encoding: Option<OracleUuidEncoding>,
+    /// Decode unconstrained `NUMBER` columns, including expressions such as
+    /// `SUM(amount)` or `COUNT(*)`, as `Decimal` even when the value is
+    /// integral, so money columns never turn into integers by accident.
+    #[serde(default)]
+    pub prefer_decimal: bool,
+}
+
+/// Representation `bool` values are bound with.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum OracleBoolEncoding {
+    /// SQL and PL/SQL `BOOLEAN`, Oracle 23ai and later.
+    Native,
+    /// `1` and `0`, for `NUMBER(1)` columns.
+    #[default]
+    Number,
+    /// `'Y'` and `'N'`, for `CHAR(1)` columns.
+    YesNo,
+    /// `'T'` and `'F'`, for `CHAR(1)` columns.
+    TrueFalse,
+}
+
+/// Column layout UUIDs are stored in, used alike for binds and decoding.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum OracleUuidEncoding {
+    /// The 16 bytes in a `RAW(16)` column, as `SYS_GUID()` produces.
+    Raw16,
+    /// Lowercase hyphenated text in a `VARCHAR2(36)` or `CHAR(36)` column.
+    Hyphenated,
+    /// 32 uppercase hex digits in a `VARCHAR2(32)` or `CHAR(32)` column, the
+    /// way `RAWTOHEX(SYS_GUID())` renders it.
+    SysGuid,
+}
+
+impl OracleBoolEncoding {
+    /// Text form of `value` under this convention.
+    pub(crate) fn literal(self, value: bool) -> &'static str {
+        match (self, value) {
+            (Self::Native, true) => "TRUE",
+            (Self::Native, false) => "FALSE",
+            (Self::Number, true) => "1",
+            (Self::Number, false) => "0",
+            (Self::YesNo, true) => "Y",
+            (Self::YesNo, false) => "N",
+            (Self::TrueFalse, true) => "T",
+            (Self::TrueFalse, false) => "F",
+        }
+    }
+
+    /// Reads one of this convention's literals back, ignoring case.
+    pub(crate) fn parse(self, text: &str) -> Option<bool> {
+        [true, false]
+            .into_iter()
+            .find(|value| text.trim().eq_ignore_ascii_case(self.literal(*value)))
+    }
+}
+
+/// Transaction mode set with `SET TRANSACTION` when a transaction begins.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum OracleIsolationLevel {
+    ReadCommitted,
+    Serializable,
+    ReadOnly,
+}
+
+impl OracleIsolationLevel {
+    pub(crate) fn set_transaction_sql(self) -> &'static str {
+        match self {
+            Self::ReadCommitted => "set transaction isolation level read committed",
+            Self::Serializable => "set transaction isolation level serializable",
+            Self::ReadOnly => "set transaction read only",
+        }
+    }
+}
+
+impl Display for OracleIsolationLevel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::ReadCommitted => "read committed",
+            Self::Serializable => "serializable",
+            Self::ReadOnly => "read only",
+        })
+    }
+}
+
+impl FromStr for OracleIsolationLevel {
+    type Err = Error;
+
+    /// Accepts `read committed`, `serializable` and `read only`, optionally
+    /// prefixed with `isolation level`.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let words: Vec<String> = value
+            .split_ascii_whitespace()
+            .map(str::to_ascii_lowercase)
+            .collect();
+        let words = match words.as_slice() {
+            [isolation, level, rest @ ..] if isolation == "isolation" && level == "level" => rest,
+            words => words,
+        };
+
+        match words {
+            [read, committed] if read == "read" && committed == "committed" => {
+                Ok(Self::ReadCommitted)
+            }
+            [serializable] if serializable == "serializable" => Ok(Self::Serializable),
+            [read, only] if read == "read" && only == "only" => Ok(Self::ReadOnly),
+            _ => Err(Error::from(format!("unknown isolation level: {value}"))),
+        }
+    }
 }
 
 impl Default for OracleConnectOptions {
@@ -40,6 +176,12 @@ impl OracleConnectOptions {
             statement_cache_capacity: Self::default_statement_cache_capacity(),
             row_channel_size: Self::default_row_channel_size(),
             command_channel_size: Self::default_command_channel_size(),
+            autocommit: Self::default_autocommit(),
+            isolation_level: None,
+            bool_fallback: OracleBoolEncoding::default(),
+            bool_encoding: None,
+            uuid_encoding: None,
+            prefer_decimal: false,
         }
     }
 
@@ -66,6 +208,10 @@ impl OracleConnectOptions {
         50
     }
 
+    fn default_autocommit() -> bool {
+        true
+    }
+
     pub fn username(mut self, username: impl Into<String>) -> Self {
         self.username = username.into();
         self
@@ -95,6 +241,36 @@ impl OracleConnectOptions {
         self.command_channel_size = size;
         self
     }
+
+    pub fn autocommit(mut self, autocommit: bool) -> Self {
+        self.autocommit = autocommit;
+        self
+    }
+
+    pub fn isolation_level(mut self, level: OracleIsolationLevel) -> Self {
+        self.isolation_level = Some(level);
+        self
+    }
+
+    pub fn bool_fallback(mut self, encoding: OracleBoolEncoding) -> Self {
+        self.bool_fallback = encoding;
+        self
+    }
+
+    pub fn bool_encoding(mut self, encoding: OracleBoolEncoding) -> Self {
+        self.bool_encoding = Some(encoding);
+        self
+    }
+
+    pub fn uuid_encoding(mut self, encoding: OracleUuidEncoding) -> Self {
+        self.uuid_encoding = Some(encoding);
+        self
+    }
+
+    pub fn prefer_decimal(mut self, prefer_decimal: bool) -> Self {
+        self.prefer_decimal = prefer_decimal;
+        self
+    }
 }
 
 impl ConnectOptions for OracleConnectOptions {
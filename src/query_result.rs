@@ -1,3 +1,4 @@
+use crate::OracleBatchError;
 use rbdc::db::ExecResult;
 use rbs::Value;
 
@@ -5,6 +6,9 @@ use rbs::Value;
 pub struct OracleQueryResult {
     pub(crate) rows_affected: u64,
     pub(crate) out_values: Vec<Value>,
+    pub(crate) row_counts: Vec<u64>,
+    pub(crate) batch_errors: Vec<OracleBatchError>,
+    pub(crate) returned_values: Vec<Vec<Value>>,
 }
 
 impl OracleQueryResult {
@@ -16,10 +20,48 @@ impl OracleQueryResult {
         &self.out_values
     }
 
+    /// Affected rows of each row of a batch execution, in input order.
+    pub fn row_counts(&self) -> &[u64] {
+        &self.row_counts
+    }
+
+    /// Rows rejected by a batch executed with batch errors enabled.
+    pub fn batch_errors(&self) -> &[OracleBatchError] {
+        &self.batch_errors
+    }
+
+    /// Values of a `RETURNING ... INTO` clause, one row per affected row.
+    pub fn returned_values(&self) -> &[Vec<Value>] {
+        &self.returned_values
+    }
+
+    /// Reports `RETURNING INTO` values as `last_insert_id`: a single value
+    /// for one row and one column, otherwise an array per row and an array
+    /// of rows. Other statements report their OUT values as an array.
     pub fn into_exec_result(self) -> ExecResult {
+        if self.returned_values.is_empty() {
+            return ExecResult {
+                rows_affected: self.rows_affected,
+                last_insert_id: Value::Array(self.out_values),
+            };
+        }
+
+        let mut rows: Vec<Value> = self
+            .returned_values
+            .into_iter()
+            .map(|mut row| match row.len() {
+                1 => row.remove(0),
+                _ => Value::Array(row),
+            })
+            .collect();
+        let last_insert_id = match rows.len() {
+            1 => rows.remove(0),
+            _ => Value::Array(rows),
+        };
+
         ExecResult {
             rows_affected: self.rows_affected,
-            last_insert_id: Value::Array(self.out_values),
+            last_insert_id,
         }
     }
 }
@@ -28,7 +70,62 @@ impl Extend<OracleQueryResult> for OracleQueryResult {
     fn extend<T: IntoIterator<Item = OracleQueryResult>>(&mut self, iter: T) {
         for elem in iter {
             self.rows_affected += elem.rows_affected;
-            self.out_values = elem.out_values;
+            self.out_values.extend(elem.out_values);
+            self.row_counts.extend(elem.row_counts);
+            self.batch_errors.extend(elem.batch_errors);
+            self.returned_values.extend(elem.returned_values);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::OracleQueryResult;
+    use rbs::Value;
+
+    #[test]
+    fn test_out_values_with_ref_cursor() {
+        // `begin :1 := 5; open :2 for select ...; end;` reports the block
+        // once; the cursor's rows follow without a result of their own.
+        let block = OracleQueryResult {
+            rows_affected: 1,
+            out_values: vec![Value::I32(5), Value::Null],
+            ..Default::default()
+        };
+        let mut result = OracleQueryResult::default();
+        result.extend([block, OracleQueryResult::default()]);
+
+        let result = result.into_exec_result();
+        assert_eq!(result.rows_affected, 1);
+        assert_eq!(
+            result.last_insert_id,
+            Value::Array(vec![Value::I32(5), Value::Null])
+        );
+    }
+
+    #[test]
+    fn test_returned_values_exec_result() {
+        let result = OracleQueryResult {
+            rows_affected: 1,
+            returned_values: vec![vec![Value::I32(7)]],
+            ..Default::default()
+        };
+        assert_eq!(result.into_exec_result().last_insert_id, Value::I32(7));
+
+        let result = OracleQueryResult {
+            rows_affected: 2,
+            returned_values: vec![
+                vec![Value::I32(1), Value::String("a".to_owned())],
+                vec![Value::I32(2), Value::String("b".to_owned())],
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            result.into_exec_result().last_insert_id,
+            Value::Array(vec![
+                Value::Array(vec![Value::I32(1), Value::String("a".to_owned())]),
+                Value::Array(vec![Value::I32(2), Value::String("b".to_owned())]),
+            ])
+        );
+    }
+}
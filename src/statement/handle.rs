@@ -1,16 +1,30 @@
-#[derive(Debug, Clone)]
+use crate::OracleError;
+use crate::connection::ConnectionHandle;
+use rbdc::Error;
+
+/// A statement built on the server, kept alive so later executions skip the
+/// parse step.
+#[derive(Debug)]
 pub(crate) struct OracleStatementHandle {
-    sql: String,
+    statement: oracle::Statement,
 }
 
 impl OracleStatementHandle {
-    pub(crate) fn new(sql: &str) -> Self {
-        Self {
-            sql: sql.to_owned(),
-        }
+    pub(crate) fn new(conn: &ConnectionHandle, sql: &str) -> Result<Self, Error> {
+        let statement = conn
+            .connection()
+            .statement(sql)
+            .build()
+            .map_err(|e| Error::from(OracleError::from(e)))?;
+
+        Ok(Self { statement })
+    }
+
+    pub(crate) fn statement(&self) -> &oracle::Statement {
+        &self.statement
     }
 
-    pub(crate) fn sql(&self) -> &str {
-        &self.sql
+    pub(crate) fn statement_mut(&mut self) -> &mut oracle::Statement {
+        &mut self.statement
     }
 }
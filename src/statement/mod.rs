@@ -6,7 +6,7 @@ mod handle;
 mod r#virtual;
 
 pub(crate) use handle::OracleStatementHandle;
-pub(crate) use r#virtual::VirtualStatement;
+pub(crate) use r#virtual::{PreparedStatement, VirtualStatement};
 
 #[derive(Debug, Clone)]
 pub struct OracleStatement {
@@ -35,12 +35,12 @@ impl VirtualStatement {
 
     pub(crate) fn prepare_next(
         &mut self,
-        _conn: &mut ConnectionHandle,
+        conn: &mut ConnectionHandle,
     ) -> Result<Option<PreparedStatement<'_>>, Error> {
         self.index = self.index.map(|index| index + 1).or(Some(0));
 
         while self.handles.len() <= self.index.unwrap_or(0) {
-            let Some(sql) = self.sql.take() else {
+            let Some(sql) = self.sql.as_deref() else {
                 return Ok(None);
             };
 
@@ -48,7 +48,10 @@ impl VirtualStatement {
                 return Ok(None);
             }
 
-            self.handles.push(OracleStatementHandle::new(&sql));
+            // Only give up the SQL once the statement is built, so a failed
+            // build can be retried on the next execution of a cached entry.
+            self.handles.push(OracleStatementHandle::new(conn, sql)?);
+            self.sql = None;
             self.columns.push(Arc::new(Vec::with_capacity(0)));
         }
 
@@ -1,4 +1,6 @@
+use crate::types::json::{json_text_len, json_type_info};
 use oracle::sql_type::OracleType;
+use rbdc::Error;
 use rbs::Value;
 use std::fmt::{self, Display, Formatter};
 
@@ -43,6 +45,13 @@ impl OracleTypeInfo {
         }
     }
 
+    pub fn boolean() -> Self {
+        Self {
+            oracle_type: None,
+            name: "BOOLEAN".to_owned(),
+        }
+    }
+
     pub fn float() -> Self {
         Self {
             oracle_type: None,
@@ -50,6 +59,20 @@ impl OracleTypeInfo {
         }
     }
 
+    pub fn binary_float() -> Self {
+        Self {
+            oracle_type: None,
+            name: "BINARY_FLOAT".to_owned(),
+        }
+    }
+
+    pub fn binary_double() -> Self {
+        Self {
+            oracle_type: None,
+            name: "BINARY_DOUBLE".to_owned(),
+        }
+    }
+
     pub fn text() -> Self {
         Self {
             oracle_type: None,
@@ -57,6 +80,13 @@ impl OracleTypeInfo {
         }
     }
 
+    pub fn clob() -> Self {
+        Self {
+            oracle_type: None,
+            name: "CLOB".to_owned(),
+        }
+    }
+
     pub fn binary() -> Self {
         Self {
             oracle_type: None,
@@ -71,6 +101,20 @@ impl OracleTypeInfo {
         }
     }
 
+    pub fn rowid() -> Self {
+        Self {
+            oracle_type: None,
+            name: "ROWID".to_owned(),
+        }
+    }
+
+    pub fn interval() -> Self {
+        Self {
+            oracle_type: None,
+            name: "INTERVAL".to_owned(),
+        }
+    }
+
     pub fn oracle_type(&self) -> Option<&OracleType> {
         self.oracle_type.as_ref()
     }
@@ -80,6 +124,76 @@ impl OracleTypeInfo {
     }
 }
 
+/// Parses an Oracle type name such as `NUMBER(10, 2)`, `VARCHAR2(100)` or
+/// `TIMESTAMP WITH TIME ZONE`, as used to declare OUT binds.
+pub(crate) fn parse_oracle_type(name: &str) -> Result<OracleType, Error> {
+    let invalid = || Error::from(format!("unsupported oracle type: {name}"));
+    let upper = name.trim().to_ascii_uppercase();
+    let (base, args) = match upper.split_once('(') {
+        Some((base, rest)) => {
+            let (args, suffix) = rest.split_once(')').ok_or_else(invalid)?;
+            let args = args
+                .split(',')
+                .map(|arg| arg.trim().parse::<i16>().map_err(|_| invalid()))
+                .collect::<Result<Vec<_>, _>>()?;
+            (format!("{}{}", base.trim_end(), suffix), args)
+        }
+        None => (upper.clone(), Vec::new()),
+    };
+    let base = base.split_ascii_whitespace().collect::<Vec<_>>().join(" ");
+    let size = |default: u32| match args.as_slice() {
+        [] => Ok(default),
+        [size] => u32::try_from(*size).map_err(|_| invalid()),
+        _ => Err(invalid()),
+    };
+    let precision = |default: u8| match args.as_slice() {
+        [] => Ok(default),
+        [precision] => u8::try_from(*precision).map_err(|_| invalid()),
+        _ => Err(invalid()),
+    };
+
+    let oracle_type = match base.as_str() {
+        "NUMBER" | "NUMERIC" | "DECIMAL" => match args.as_slice() {
+            [] => OracleType::Number(0, -127),
+            [precision] => OracleType::Number(u8::try_from(*precision).map_err(|_| invalid())?, 0),
+            [precision, scale] => OracleType::Number(
+                u8::try_from(*precision).map_err(|_| invalid())?,
+                i8::try_from(*scale).map_err(|_| invalid())?,
+            ),
+            _ => return Err(invalid()),
+        },
+        "INTEGER" | "INT" | "SMALLINT" if args.is_empty() => OracleType::Number(38, 0),
+        "FLOAT" => OracleType::Float(precision(126)?),
+        "BINARY_FLOAT" if args.is_empty() => OracleType::BinaryFloat,
+        "BINARY_DOUBLE" if args.is_empty() => OracleType::BinaryDouble,
+        "VARCHAR2" | "VARCHAR" => OracleType::Varchar2(size(4000)?),
+        "NVARCHAR2" => OracleType::NVarchar2(size(2000)?),
+        "CHAR" => OracleType::Char(size(1)?),
+        "NCHAR" => OracleType::NChar(size(1)?),
+        "RAW" => OracleType::Raw(size(2000)?),
+        "DATE" if args.is_empty() => OracleType::Date,
+        "TIMESTAMP" => OracleType::Timestamp(precision(6)?),
+        "TIMESTAMP WITH TIME ZONE" => OracleType::TimestampTZ(precision(6)?),
+        "TIMESTAMP WITH LOCAL TIME ZONE" => OracleType::TimestampLTZ(precision(6)?),
+        "INTERVAL YEAR TO MONTH" if args.is_empty() => OracleType::IntervalYM(2),
+        "INTERVAL DAY TO SECOND" if args.is_empty() => OracleType::IntervalDS(2, 6),
+        "CLOB" if args.is_empty() => OracleType::CLOB,
+        "NCLOB" if args.is_empty() => OracleType::NCLOB,
+        "BLOB" if args.is_empty() => OracleType::BLOB,
+        "LONG" if args.is_empty() => OracleType::Long,
+        "LONG RAW" if args.is_empty() => OracleType::LongRaw,
+        "ROWID" if args.is_empty() => OracleType::Rowid,
+        "UROWID" => {
+            size(4000)?;
+            OracleType::Rowid
+        }
+        "BOOLEAN" if args.is_empty() => OracleType::Boolean,
+        "SYS_REFCURSOR" | "REF CURSOR" if args.is_empty() => OracleType::RefCursor,
+        _ => return Err(invalid()),
+    };
+    Ok(oracle_type)
+}
+
 impl Display for OracleTypeInfo {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.pad(self.name())
@@ -90,20 +204,24 @@ impl Type for Value {
     fn type_info(&self) -> OracleTypeInfo {
         match self {
             Value::Null => OracleTypeInfo::null(),
-            Value::Bool(_) => OracleTypeInfo::number(),
+            Value::Bool(_) => OracleTypeInfo::boolean(),
             Value::I32(_) | Value::I64(_) | Value::U32(_) | Value::U64(_) => {
                 OracleTypeInfo::number()
             }
-            Value::F32(_) | Value::F64(_) => OracleTypeInfo::float(),
+            Value::F32(_) => OracleTypeInfo::binary_float(),
+            Value::F64(_) => OracleTypeInfo::binary_double(),
             Value::String(_) => OracleTypeInfo::text(),
             Value::Binary(_) => OracleTypeInfo::binary(),
             Value::Array(_) | Value::Map(_) => OracleTypeInfo::text(),
-            Value::Ext(type_name, _) => match *type_name {
-                "Date" | "DateTime" | "Time" => OracleTypeInfo::date(),
-                "Timestamp" => OracleTypeInfo::number(),
+            Value::Ext(type_name, value) => match *type_name {
+                "Date" | "DateTime" | "Time" | "Timestamp" => OracleTypeInfo::date(),
                 "Decimal" => OracleTypeInfo::number(),
-                "Json" => OracleTypeInfo::binary(),
+                "Json" => json_type_info(json_text_len(value)),
+                // Text unless `uuid_encoding` selects `RAW(16)`, which the
+                // connection applies only when binding.
                 "Uuid" => OracleTypeInfo::text(),
+                "Interval" => OracleTypeInfo::interval(),
+                "RowId" => OracleTypeInfo::rowid(),
                 _ => OracleTypeInfo::null(),
             },
         }
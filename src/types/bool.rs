@@ -1,25 +1,57 @@
 use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::type_info::{OracleTypeInfo, Type};
-use crate::{OracleArgumentValue, OracleValueRef};
+use crate::{OracleArgumentValue, OracleBoolEncoding, OracleValueRef};
+use oracle::sql_type::OracleType;
 use rbdc::Error;
 
+/// Reads a flag column stored in the connection's explicit bool convention,
+/// e.g. `CHAR(1)` `'Y'`/`'N'`. Columns of another shape and values other
+/// than the convention's two literals are left to the usual decoding.
+pub(crate) fn decode_stored_bool(
+    oracle_type: &OracleType,
+    value: OracleValueRef<'_>,
+) -> Option<bool> {
+    let encoding = value.options().stored_bools?;
+    let stored = match encoding {
+        OracleBoolEncoding::Native => false,
+        OracleBoolEncoding::Number => matches!(oracle_type, OracleType::Number(1, 0)),
+        OracleBoolEncoding::YesNo | OracleBoolEncoding::TrueFalse => matches!(
+            oracle_type,
+            OracleType::Char(1)
+                | OracleType::NChar(1)
+                | OracleType::Varchar2(1)
+                | OracleType::NVarchar2(1)
+        ),
+    };
+    if !stored {
+        return None;
+    }
+    encoding.parse(value.text().ok()?)
+}
+
 impl Type for bool {
     fn type_info(&self) -> OracleTypeInfo {
-        OracleTypeInfo::number()
+        OracleTypeInfo::boolean()
     }
 }
 
 impl Encode for bool {
     fn encode(self, args: &mut Vec<OracleArgumentValue>) -> Result<IsNull, Error> {
-        args.push(OracleArgumentValue::String(self.to_string()));
+        args.push(OracleArgumentValue::Bool(self));
         Ok(IsNull::No)
     }
 }
 
 impl Decode for bool {
     fn decode(value: OracleValueRef<'_>) -> Result<Self, Error> {
-        let value = value.text()?;
-        Ok(matches!(value, "1" | "true" | "TRUE" | "Y" | "y"))
+        let text = value.text()?;
+        match value.options().stored_bools {
+            Some(encoding) => encoding
+                .parse(text)
+                .or_else(|| OracleBoolEncoding::Native.parse(text))
+                .ok_or_else(|| Error::from(format!("invalid {encoding:?} bool value: {text}"))),
+            None => Ok(matches!(text, "1" | "true" | "TRUE" | "Y" | "y")),
+        }
     }
 }
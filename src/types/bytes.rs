@@ -2,9 +2,18 @@ use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::type_info::{OracleTypeInfo, Type};
 use crate::{OracleArgumentValue, OracleValueRef};
+use oracle::sql_type::OracleType;
 use rbdc::Error;
 use rbs::Value;
 
+/// Whether values of `oracle_type` are fetched as bytes rather than text.
+pub(crate) fn is_binary(oracle_type: &OracleType) -> bool {
+    matches!(
+        oracle_type,
+        OracleType::BLOB | OracleType::Raw(_) | OracleType::LongRaw
+    )
+}
+
 pub(crate) fn decode_binary(value: OracleValueRef<'_>) -> Value {
     value
         .blob()
@@ -0,0 +1,107 @@
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::type_info::{OracleTypeInfo, Type};
+use crate::{OracleArgumentValue, OracleValueRef};
+use chrono::{FixedOffset, Utc};
+use oracle::sql_type::{OracleType, Timestamp as OracleTimestamp};
+use rbdc::Error;
+use rbdc::datetime::DateTime;
+use rbdc::timestamp::Timestamp;
+use std::str::FromStr;
+
+/// Whether values of `oracle_type` are fetched as [`OracleTimestamp`] and
+/// carried as [`timestamp_text`].
+pub(crate) fn is_datetime(oracle_type: &OracleType) -> bool {
+    matches!(
+        oracle_type,
+        OracleType::Date
+            | OracleType::Timestamp(_)
+            | OracleType::TimestampTZ(_)
+            | OracleType::TimestampLTZ(_)
+    )
+}
+
+/// Formats `value` as RFC 3339 with nanoseconds, independent of the session's
+/// NLS settings. Zoned types keep their offset; `DATE` and `TIMESTAMP` carry
+/// none and are read in the local zone like before.
+pub(crate) fn timestamp_text(value: &OracleTimestamp, oracle_type: &OracleType) -> String {
+    let mut text = format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:09}",
+        value.year(),
+        value.month(),
+        value.day(),
+        value.hour(),
+        value.minute(),
+        value.second(),
+        value.nanosecond(),
+    );
+    if matches!(
+        oracle_type,
+        OracleType::TimestampTZ(_) | OracleType::TimestampLTZ(_)
+    ) {
+        let offset = value.tz_offset();
+        let sign = if offset < 0 { '-' } else { '+' };
+        let offset = offset.abs();
+        text.push_str(&format!(
+            "{sign}{:02}:{:02}",
+            offset / 3600,
+            offset % 3600 / 60
+        ));
+    }
+    text
+}
+
+/// Parses any string form [`DateTime`] accepts into a zoned value, bound as
+/// `TIMESTAMP WITH TIME ZONE` with nanosecond precision.
+pub(crate) fn parse_datetime(value: &str) -> Result<chrono::DateTime<FixedOffset>, Error> {
+    let datetime = DateTime::from_str(value)?;
+    let offset = FixedOffset::east_opt(datetime.offset())
+        .ok_or_else(|| Error::from(format!("invalid offset in datetime: {value}")))?;
+    let utc = chrono::DateTime::<Utc>::from_timestamp(datetime.unix_timestamp(), datetime.nano())
+        .ok_or_else(|| Error::from(format!("datetime out of range: {value}")))?;
+    Ok(utc.with_timezone(&offset))
+}
+
+/// Converts rbdc's milliseconds since the Unix epoch into a UTC timestamp.
+pub(crate) fn from_timestamp_millis(millis: i64) -> Result<chrono::DateTime<Utc>, Error> {
+    chrono::DateTime::<Utc>::from_timestamp_millis(millis)
+        .ok_or_else(|| Error::from(format!("timestamp out of range: {millis}")))
+}
+
+impl Type for DateTime {
+    fn type_info(&self) -> OracleTypeInfo {
+        OracleTypeInfo::date()
+    }
+}
+
+impl Encode for DateTime {
+    fn encode(self, args: &mut Vec<OracleArgumentValue>) -> Result<IsNull, Error> {
+        args.push(OracleArgumentValue::DateTime(self.0.to_string()));
+        Ok(IsNull::No)
+    }
+}
+
+impl Decode for DateTime {
+    fn decode(value: OracleValueRef<'_>) -> Result<Self, Error> {
+        DateTime::from_str(value.text()?)
+    }
+}
+
+impl Type for Timestamp {
+    fn type_info(&self) -> OracleTypeInfo {
+        OracleTypeInfo::date()
+    }
+}
+
+impl Encode for Timestamp {
+    fn encode(self, args: &mut Vec<OracleArgumentValue>) -> Result<IsNull, Error> {
+        args.push(OracleArgumentValue::Timestamp(self.0));
+        Ok(IsNull::No)
+    }
+}
+
+impl Decode for Timestamp {
+    fn decode(value: OracleValueRef<'_>) -> Result<Self, Error> {
+        Ok(Timestamp::from(DateTime::decode(value)?))
+    }
+}
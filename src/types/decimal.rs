@@ -0,0 +1,133 @@
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::type_info::{OracleTypeInfo, Type};
+use crate::types::value::MISSING_STRING_VALUE;
+use crate::{OracleArgumentValue, OracleValueRef};
+use bigdecimal::{BigDecimal, ToPrimitive};
+use rbdc::Error;
+use rbdc::decimal::Decimal;
+use rbs::Value;
+use std::str::FromStr;
+
+/// Parses `NUMBER` text into an exact decimal.
+///
+/// Fetched values arrive in ODPI-C's canonical form, plain digits with a `.`
+/// separator whatever the session's NLS settings. Anything else, such as
+/// digit grouping or a `,` decimal separator, is rejected rather than guessed.
+/// A leading `+`, as callers may write in `Decimal` arguments, is dropped.
+pub(crate) fn parse_number(text: &str) -> Result<BigDecimal, Error> {
+    let invalid = || Error::from(format!("invalid number: {text}"));
+    let unsigned = text
+        .strip_prefix('+')
+        .filter(|rest| !rest.starts_with('-'))
+        .unwrap_or(text);
+    if !is_canonical_number(unsigned) {
+        return Err(invalid());
+    }
+    BigDecimal::from_str(unsigned).map_err(|_| invalid())
+}
+
+/// `-12.5`, `.5` or `1E+20`: an optional minus sign, digits with at most one
+/// `.`, and an optional exponent.
+fn is_canonical_number(text: &str) -> bool {
+    let digits = |text: &str| text.bytes().all(|b| b.is_ascii_digit());
+    let (mantissa, exponent) = match text.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+        None => (text, None),
+    };
+    let mantissa = mantissa.strip_prefix('-').unwrap_or(mantissa);
+    let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    (!whole.is_empty() || !fraction.is_empty())
+        && digits(whole)
+        && digits(fraction)
+        && exponent.is_none_or(|exponent| {
+            let exponent = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+            !exponent.is_empty() && digits(exponent)
+        })
+}
+
+/// `Ext("Decimal")` in plain notation, never as an exponent.
+pub(crate) fn decimal_value(decimal: &BigDecimal) -> Value {
+    Value::String(decimal.to_plain_string()).into_ext("Decimal")
+}
+
+/// Decodes `NUMBER(precision, scale)`:
+///
+/// | column                        | value                              |
+/// |-------------------------------|------------------------------------|
+/// | `NUMBER(1..=9, <= 0)`         | `I32`                              |
+/// | `NUMBER(10..=18, <= 0)`       | `I64`                              |
+/// | `NUMBER(19..=38, <= 0)`       | `Decimal`                          |
+/// | `NUMBER(p, > 0)`              | `Decimal`                          |
+/// | `NUMBER` (unconstrained)      | integers by digits, else `Decimal` |
+///
+/// A negative scale rounds to the left of the point and so adds digits. With
+/// `prefer_decimal`, unconstrained columns always decode as `Decimal`.
+pub(crate) fn decode_number(
+    value: OracleValueRef<'_>,
+    precision: u8,
+    scale: i8,
+) -> Result<Value, Error> {
+    let decimal = parse_number(
+        value
+            .text()
+            .map_err(|_| Error::from(MISSING_STRING_VALUE))?,
+    )?;
+
+    if precision == 0 && scale == -127 {
+        if !decimal.is_integer() || value.options().prefer_decimal {
+            return Ok(decimal_value(&decimal));
+        }
+        // `1E+20` keeps its zeros in a negative scale, not in `digits`.
+        let digits = decimal.digits() as i64 - decimal.fractional_digit_count().min(0);
+        return Ok(decode_integer(&decimal, digits as u64));
+    }
+
+    if scale > 0 {
+        return Ok(decimal_value(&decimal));
+    }
+
+    // `NUMBER(*, 0)` reports no precision and holds up to 38 digits.
+    let precision = if precision == 0 { 38 } else { precision };
+    let digits = u64::from(precision) + u64::from(scale.unsigned_abs());
+    Ok(decode_integer(&decimal, digits))
+}
+
+/// Picks the narrowest integer that holds every value with `digits` decimal
+/// digits, or `Decimal` past 18 digits. For a declared precision `digits`
+/// follows the column, so all its rows share one type; for unconstrained
+/// `NUMBER` it is the value's own digit count.
+fn decode_integer(decimal: &BigDecimal, digits: u64) -> Value {
+    let integer = decimal.with_scale(0);
+    if digits <= 9
+        && let Some(value) = integer.to_i32()
+    {
+        return Value::I32(value);
+    }
+    if digits <= 18
+        && let Some(value) = integer.to_i64()
+    {
+        return Value::I64(value);
+    }
+    decimal_value(&integer)
+}
+
+impl Type for Decimal {
+    fn type_info(&self) -> OracleTypeInfo {
+        OracleTypeInfo::number()
+    }
+}
+
+impl Encode for Decimal {
+    fn encode(self, args: &mut Vec<OracleArgumentValue>) -> Result<IsNull, Error> {
+        args.push(OracleArgumentValue::Decimal(self.0.to_plain_string()));
+        Ok(IsNull::No)
+    }
+}
+
+impl Decode for Decimal {
+    fn decode(value: OracleValueRef<'_>) -> Result<Self, Error> {
+        Ok(Decimal(parse_number(value.text()?)?))
+    }
+}
@@ -1,21 +1,27 @@
 use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::type_info::{OracleTypeInfo, Type};
+use crate::types::decimal::parse_number;
 use crate::{OracleArgumentValue, OracleValueRef};
+use bigdecimal::ToPrimitive;
 use rbdc::Error;
 use rbs::Value;
 
+/// Decodes the `NUMBER`-based `FLOAT(precision)`, whose binary precision
+/// picks the Rust float.
 pub(crate) fn decode_float(value: OracleValueRef<'_>, precision: u8) -> Result<Value, Error> {
-    if precision >= 24 {
-        Ok(Value::F64(f64::decode(value)?))
+    let decimal = parse_number(value.text()?)?;
+    let float = if precision >= 24 {
+        decimal.to_f64().map(Value::F64)
     } else {
-        Ok(Value::F32(f32::decode(value)?))
-    }
+        decimal.to_f32().map(Value::F32)
+    };
+    float.ok_or_else(|| Error::from(format!("FLOAT out of range: {decimal}")))
 }
 
 impl Type for f32 {
     fn type_info(&self) -> OracleTypeInfo {
-        OracleTypeInfo::float()
+        OracleTypeInfo::binary_float()
     }
 }
 
@@ -28,13 +34,17 @@ impl Encode for f32 {
 
 impl Decode for f32 {
     fn decode(value: OracleValueRef<'_>) -> Result<Self, Error> {
-        Ok(value.text()?.parse()?)
+        match value.float() {
+            // `BINARY_FLOAT` values were widened exactly when fetched.
+            Some(float) => Ok(float as f32),
+            None => Ok(value.text()?.parse()?),
+        }
     }
 }
 
 impl Type for f64 {
     fn type_info(&self) -> OracleTypeInfo {
-        OracleTypeInfo::float()
+        OracleTypeInfo::binary_double()
     }
 }
 
@@ -47,6 +57,11 @@ impl Encode for f64 {
 
 impl Decode for f64 {
     fn decode(value: OracleValueRef<'_>) -> Result<Self, Error> {
-        Ok(value.text()?.parse()?)
+        match value.float() {
+            Some(float) => Ok(float),
+            // Server-side text such as `TO_CHAR` output, which spells the
+            // special values `Nan`, `Inf` and `-Inf`.
+            None => Ok(value.text()?.parse()?),
+        }
     }
 }
@@ -1,25 +1,37 @@
 use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::type_info::{OracleTypeInfo, Type};
-use crate::types::value::MISSING_STRING_VALUE;
 use crate::{OracleArgumentValue, OracleValueRef};
-use bigdecimal::BigDecimal;
 use oracle::sql_type::OracleType;
 use rbdc::Error;
 use rbdc::datetime::DateTime;
 use rbs::Value;
-use std::str::FromStr;
 
 pub(crate) fn decode_by_oracle_type(
     oracle_type: &OracleType,
     value: OracleValueRef<'_>,
 ) -> Result<Value, Error> {
     match oracle_type {
-        OracleType::Number(precision, scale) => decode_number(value, *precision, *scale),
-        OracleType::Int64 => Ok(Value::I32(i32::decode(value)?)),
+        OracleType::Number(precision, scale) => {
+            crate::types::decimal::decode_number(value, *precision, *scale)
+        }
+        OracleType::Int64 => Ok(Value::I64(i64::decode(value)?)),
+        OracleType::UInt64 => Ok(Value::U64(u64::decode(value)?)),
         OracleType::Float(precision) => crate::types::float::decode_float(value, *precision),
-        OracleType::Date => Ok(Value::from(DateTime::from_str(value.text()?)?)),
-        OracleType::BLOB => Ok(crate::types::bytes::decode_binary(value)),
+        OracleType::BinaryFloat => Ok(Value::F32(f32::decode(value)?)),
+        OracleType::BinaryDouble => Ok(Value::F64(f64::decode(value)?)),
+        OracleType::Boolean => Ok(Value::Bool(bool::decode(value)?)),
+        OracleType::Date
+        | OracleType::Timestamp(_)
+        | OracleType::TimestampTZ(_)
+        | OracleType::TimestampLTZ(_) => Ok(Value::from(DateTime::decode(value)?)),
+        OracleType::BLOB | OracleType::Raw(_) | OracleType::LongRaw => {
+            Ok(crate::types::bytes::decode_binary(value))
+        }
+        OracleType::IntervalYM(_) | OracleType::IntervalDS(..) => {
+            Ok(Value::String(value.text()?.to_owned()).into_ext("Interval"))
+        }
+        OracleType::Rowid => Ok(Value::String(value.text()?.to_owned()).into_ext("RowId")),
         OracleType::Long | OracleType::CLOB | OracleType::NCLOB => {
             Ok(Value::String(String::decode(value)?))
         }
@@ -30,42 +42,6 @@ pub(crate) fn decode_by_oracle_type(
     }
 }
 
-fn decode_number(value: OracleValueRef<'_>, precision: u8, scale: i8) -> Result<Value, Error> {
-    let value = value
-        .text()
-        .map_err(|_| Error::from(MISSING_STRING_VALUE))?;
-
-    if precision == 0 && scale == -127 {
-        let decimal = BigDecimal::from_str(value).map_err(|e| Error::from(e.to_string()))?;
-        if decimal.is_integer() {
-            let digits = decimal.digits();
-            if (1..=9).contains(&digits) {
-                return Ok(Value::I32(value.parse()?));
-            }
-            if (10..=18).contains(&digits) {
-                return Ok(Value::I64(value.parse()?));
-            }
-        }
-        return Ok(Value::String(decimal.to_string()).into_ext("Decimal"));
-    }
-
-    if scale > 0 {
-        let decimal = BigDecimal::from_str(value).map_err(|e| Error::from(e.to_string()))?;
-        return Ok(Value::String(decimal.to_string()).into_ext("Decimal"));
-    }
-
-    if (1..=9).contains(&precision) {
-        return Ok(Value::I32(value.parse()?));
-    }
-
-    if (10..=18).contains(&precision) {
-        return Ok(Value::I64(value.parse()?));
-    }
-
-    let decimal = BigDecimal::from_str(value).map_err(|e| Error::from(e.to_string()))?;
-    Ok(Value::String(decimal.to_string()).into_ext("Decimal"))
-}
-
 impl Type for i32 {
     fn type_info(&self) -> OracleTypeInfo {
         OracleTypeInfo::number()
@@ -0,0 +1,234 @@
+use crate::OracleArgumentValue;
+use crate::OracleValueRef;
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::type_info::{OracleTypeInfo, Type};
+use oracle::sql_type::IntervalYM;
+use rbdc::Error;
+
+/// Formats a day-to-second interval as an ISO 8601 duration such as
+/// `P1DT2H3M4.5S`, the text carried by `Ext("Interval")`.
+pub(crate) fn interval_ds_text(value: &chrono::Duration) -> String {
+    let sign = if *value < chrono::Duration::zero() {
+        "-"
+    } else {
+        ""
+    };
+    let value = value.abs();
+    let secs = value.num_seconds();
+    let nanos = value.subsec_nanos();
+    let mut text = format!(
+        "{sign}P{}DT{}H{}M{}",
+        secs / 86_400,
+        secs % 86_400 / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    );
+    if nanos != 0 {
+        let fraction = format!("{nanos:09}");
+        text.push('.');
+        text.push_str(fraction.trim_end_matches('0'));
+    }
+    text.push('S');
+    text
+}
+
+/// Total signed months of a fetched year-to-month interval. `INTERVAL YEAR(9)`
+/// spans more months than fit an `i32`.
+pub(crate) fn interval_ym_months(value: &IntervalYM) -> i64 {
+    i64::from(value.years()) * 12 + i64::from(value.months())
+}
+
+/// Formats a year-to-month interval of `months` as an ISO 8601 duration such
+/// as `P1Y2M`.
+pub(crate) fn interval_ym_text(months: i64) -> String {
+    let sign = if months < 0 { "-" } else { "" };
+    let months = months.unsigned_abs();
+    format!("{sign}P{}Y{}M", months / 12, months % 12)
+}
+
+/// Parses an ISO 8601 duration into the interval kind it describes: years and
+/// months make an `INTERVAL YEAR TO MONTH`, days and time parts an
+/// `INTERVAL DAY TO SECOND`. The two cannot be mixed. Oracle's own
+/// `+1 02:03:04.5` and `+1-02` literals are accepted as well.
+pub(crate) fn parse_interval(text: &str) -> Result<OracleArgumentValue, Error> {
+    let invalid = || Error::from(format!("invalid interval: {text}"));
+    let text = text.trim();
+    let (negative, rest) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+    if !rest.starts_with('P') {
+        return parse_oracle_interval(rest, negative).ok_or_else(invalid);
+    }
+    let rest = &rest[1..];
+    let (date, time) = match rest.split_once('T') {
+        Some((date, time)) => (date, Some(time)),
+        None => (rest, None),
+    };
+
+    let mut months = 0i64;
+    let mut nanos = 0i128;
+    let mut has_months = false;
+    let mut has_days = time.is_some();
+    for (number, unit) in components(date).ok_or_else(invalid)? {
+        let number: i64 = number.parse().map_err(|_| invalid())?;
+        match unit {
+            'Y' => {
+                months = number
+                    .checked_mul(12)
+                    .and_then(|years| months.checked_add(years))
+                    .ok_or_else(invalid)?
+            }
+            'M' => months = months.checked_add(number).ok_or_else(invalid)?,
+            'D' => {
+                nanos = nanos
+                    .checked_add(number as i128 * 86_400_000_000_000)
+                    .ok_or_else(invalid)?
+            }
+            _ => return Err(invalid()),
+        }
+        has_months |= unit != 'D';
+        has_days |= unit == 'D';
+    }
+    for (number, unit) in components(time.unwrap_or_default()).ok_or_else(invalid)? {
+        let scale: i128 = match unit {
+            'H' => 3_600_000_000_000,
+            'M' => 60_000_000_000,
+            'S' => 1_000_000_000,
+            _ => return Err(invalid()),
+        };
+        let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+        if unit != 'S' && !fraction.is_empty() || fraction.len() > 9 {
+            return Err(invalid());
+        }
+        let whole: i128 = whole.parse().map_err(|_| invalid())?;
+        let fraction: i128 = format!("{fraction:0<9}").parse().map_err(|_| invalid())?;
+        nanos = whole
+            .checked_mul(scale)
+            .and_then(|whole| whole.checked_add(fraction))
+            .and_then(|whole| nanos.checked_add(whole))
+            .ok_or_else(invalid)?;
+    }
+
+    if has_months && has_days {
+        return Err(invalid());
+    }
+    if has_months {
+        let months = if negative {
+            months.checked_neg()
+        } else {
+            Some(months)
+        };
+        return months
+            .map(OracleArgumentValue::IntervalYM)
+            .ok_or_else(invalid);
+    }
+    let nanos = if negative { -nanos } else { nanos };
+    let secs = i64::try_from(nanos / 1_000_000_000).map_err(|_| invalid())?;
+    let duration = duration(secs, (nanos % 1_000_000_000) as i64).ok_or_else(invalid)?;
+    Ok(OracleArgumentValue::IntervalDS(duration))
+}
+
+fn parse_oracle_interval(text: &str, negative: bool) -> Option<OracleArgumentValue> {
+    if let Some((days, time)) = text.split_once(' ') {
+        let mut time = time.splitn(3, ':');
+        let (hours, minutes, seconds) = (time.next()?, time.next()?, time.next()?);
+        let (seconds, fraction) = seconds.split_once('.').unwrap_or((seconds, ""));
+        if fraction.len() > 9 {
+            return None;
+        }
+        let secs = days
+            .parse::<i64>()
+            .ok()?
+            .checked_mul(86_400)?
+            .checked_add(hours.parse::<i64>().ok()?.checked_mul(3600)?)?
+            .checked_add(minutes.parse::<i64>().ok()?.checked_mul(60)?)?
+            .checked_add(seconds.parse::<i64>().ok()?)?;
+        let nanos: i64 = format!("{fraction:0<9}").parse().ok()?;
+        let duration = duration(secs, nanos)?;
+        return Some(OracleArgumentValue::IntervalDS(if negative {
+            -duration
+        } else {
+            duration
+        }));
+    }
+
+    let (years, months) = text.split_once('-')?;
+    let months = years
+        .parse::<i64>()
+        .ok()?
+        .checked_mul(12)?
+        .checked_add(months.parse::<i64>().ok()?)?;
+    let months = if negative {
+        months.checked_neg()?
+    } else {
+        months
+    };
+    Some(OracleArgumentValue::IntervalYM(months))
+}
+
+/// `secs` seconds plus `nanos` nanoseconds, or `None` past `chrono`'s range.
+fn duration(secs: i64, nanos: i64) -> Option<chrono::Duration> {
+    chrono::Duration::try_seconds(secs)?.checked_add(&chrono::Duration::nanoseconds(nanos))
+}
+
+/// Splits `1Y2M` into `[("1", 'Y'), ("2", 'M')]`.
+fn components(text: &str) -> Option<Vec<(&str, char)>> {
+    let mut components = Vec::new();
+    let mut start = 0;
+    for (index, c) in text.char_indices() {
+        if c.is_ascii_alphabetic() {
+            let number = &text[start..index];
+            if number.is_empty() {
+                return None;
+            }
+            components.push((number, c.to_ascii_uppercase()));
+            start = index + c.len_utf8();
+        }
+    }
+    (start == text.len()).then_some(components)
+}
+
+impl Type for chrono::Duration {
+    fn type_info(&self) -> OracleTypeInfo {
+        OracleTypeInfo::interval()
+    }
+}
+
+impl Encode for chrono::Duration {
+    fn encode(self, args: &mut Vec<OracleArgumentValue>) -> Result<IsNull, Error> {
+        args.push(OracleArgumentValue::IntervalDS(self));
+        Ok(IsNull::No)
+    }
+}
+
+impl Decode for chrono::Duration {
+    fn decode(value: OracleValueRef<'_>) -> Result<Self, Error> {
+        match parse_interval(value.text()?)? {
+            OracleArgumentValue::IntervalDS(value) => Ok(value),
+            _ => Err(Error::from("expected an interval day to second")),
+        }
+    }
+}
+
+impl Type for std::time::Duration {
+    fn type_info(&self) -> OracleTypeInfo {
+        OracleTypeInfo::interval()
+    }
+}
+
+impl Encode for std::time::Duration {
+    fn encode(self, args: &mut Vec<OracleArgumentValue>) -> Result<IsNull, Error> {
+        let value = chrono::Duration::from_std(self).map_err(|e| Error::from(e.to_string()))?;
+        value.encode(args)
+    }
+}
+
+impl Decode for std::time::Duration {
+    fn decode(value: OracleValueRef<'_>) -> Result<Self, Error> {
+        chrono::Duration::decode(value)?
+            .to_std()
+            .map_err(|e| Error::from(e.to_string()))
+    }
+}
@@ -0,0 +1,121 @@
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::type_info::{OracleTypeInfo, Type};
+use crate::{OracleArgumentValue, OracleValueRef};
+use rbdc::Error;
+use rbdc::json::{Json, JsonV};
+use rbs::Value;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+/// Largest JSON text still bound as `VARCHAR2`; longer documents go as `CLOB`.
+pub(crate) const MAX_JSON_VARCHAR_LEN: usize = 4000;
+
+/// Serializes the payload of `Ext("Json", ...)`: JSON text (as produced by
+/// `rbdc::types::json::Json`) is kept as is, structured values are encoded.
+///
+/// The oracle crate cannot fetch the native `JSON` type and fails such
+/// queries, so documents are read back as text from `IS JSON` columns or via
+/// `JSON_SERIALIZE(... RETURNING CLOB)`, and parsed by `Json` and `JsonV`.
+pub(crate) fn json_text(value: Value) -> Result<String, Error> {
+    match value {
+        Value::String(text) => Ok(text),
+        Value::Binary(bytes) => String::from_utf8(bytes).map_err(|e| Error::from(e.to_string())),
+        value => serde_json::to_string(&value).map_err(|e| Error::from(e.to_string())),
+    }
+}
+
+/// Length of the text [`json_text`] produces for `value`.
+pub(crate) fn json_text_len(value: &Value) -> usize {
+    match value {
+        Value::String(text) => text.len(),
+        Value::Binary(bytes) => bytes.len(),
+        value => serde_json::to_string(value).map_or(0, |text| text.len()),
+    }
+}
+
+/// `VARCHAR2`, or `CLOB` for documents long enough to be bound as one.
+pub(crate) fn json_type_info(len: usize) -> OracleTypeInfo {
+    if len > MAX_JSON_VARCHAR_LEN {
+        OracleTypeInfo::clob()
+    } else {
+        OracleTypeInfo::text()
+    }
+}
+
+/// Parses a JSON document into maps, arrays and scalars.
+pub(crate) fn decode_json(text: &str) -> Result<Value, Error> {
+    let value: serde_json::Value =
+        serde_json::from_str(text).map_err(|e| Error::from(e.to_string()))?;
+    Ok(from_json(value))
+}
+
+// Integers come back signed like NUMBER columns do, not as serde's `u64`.
+fn from_json(value: serde_json::Value) -> Value {
+    match value {
+        serde_json::Value::Null => Value::Null,
+        serde_json::Value::Bool(value) => Value::Bool(value),
+        serde_json::Value::Number(number) => match (number.as_i64(), number.as_u64()) {
+            (Some(value), _) => Value::I64(value),
+            (None, Some(value)) => Value::U64(value),
+            _ => Value::F64(number.as_f64().unwrap_or_default()),
+        },
+        serde_json::Value::String(value) => Value::String(value),
+        serde_json::Value::Array(values) => {
+            Value::Array(values.into_iter().map(from_json).collect())
+        }
+        serde_json::Value::Object(entries) => {
+            let mut map = rbs::value::map::ValueMap::with_capacity(entries.len());
+            for (key, value) in entries {
+                map.insert(Value::String(key), from_json(value));
+            }
+            Value::Map(map)
+        }
+    }
+}
+
+impl Type for Json {
+    fn type_info(&self) -> OracleTypeInfo {
+        json_type_info(self.0.len())
+    }
+}
+
+impl Encode for Json {
+    fn encode(self, args: &mut Vec<OracleArgumentValue>) -> Result<IsNull, Error> {
+        args.push(OracleArgumentValue::Json(self.0));
+        Ok(IsNull::No)
+    }
+}
+
+impl Decode for Json {
+    /// Checks that the `VARCHAR2` or `CLOB` text is a JSON document.
+    fn decode(value: OracleValueRef<'_>) -> Result<Self, Error> {
+        let text = value.text()?;
+        decode_json(text)?;
+        Ok(Json(text.to_owned()))
+    }
+}
+
+impl<T: Serialize + DeserializeOwned> Type for JsonV<T> {
+    fn type_info(&self) -> OracleTypeInfo {
+        json_type_info(serde_json::to_string(&self.0).map_or(0, |text| text.len()))
+    }
+}
+
+impl<T: Serialize + DeserializeOwned> Encode for JsonV<T> {
+    fn encode(self, args: &mut Vec<OracleArgumentValue>) -> Result<IsNull, Error> {
+        let text = serde_json::to_string(&self.0).map_err(|e| Error::from(e.to_string()))?;
+        args.push(OracleArgumentValue::Json(text));
+        Ok(IsNull::No)
+    }
+}
+
+impl<T: Serialize + DeserializeOwned> Decode for JsonV<T> {
+    /// Parses the `VARCHAR2` or `CLOB` text and deserializes `T` from it.
+    fn decode(value: OracleValueRef<'_>) -> Result<Self, Error> {
+        let document = decode_json(value.text()?)?;
+        rbs::from_value(document)
+            .map(JsonV)
+            .map_err(|e| Error::from(e.to_string()))
+    }
+}
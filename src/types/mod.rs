@@ -1,15 +1,259 @@
 //! Conversions between Rust and Oracle types.
 
-mod bool;
-mod bytes;
+pub(crate) mod bool;
+pub(crate) mod bytes;
+pub(crate) mod datetime;
+pub(crate) mod decimal;
 mod float;
 mod int;
+pub(crate) mod interval;
+pub(crate) mod json;
 mod str;
 mod uint;
+pub(crate) mod uuid;
 pub(crate) mod value;
 
 #[cfg(test)]
 mod test {
+    use crate::decode::Decode;
+    use crate::types::datetime::{from_timestamp_millis, parse_datetime, timestamp_text};
+    use crate::types::interval::{interval_ds_text, interval_ym_months, interval_ym_text};
+    use crate::types::uuid::{parse_uuid, uuid_text};
+    use crate::value::ValueOptions;
+    use crate::{OracleBoolEncoding, OracleTypeInfo, OracleUuidEncoding, OracleValue};
+    use oracle::sql_type::{IntervalYM, OracleType, Timestamp};
+    use rbdc::decimal::Decimal;
+    use rbs::Value;
+
+    fn text_value(text: &str, ty: OracleType) -> OracleValue {
+        OracleValue::new(
+            Some(text.to_owned()),
+            None,
+            OracleTypeInfo::from_oracle_type(ty),
+            false,
+        )
+    }
+
+    /// The bytes of a column of type `ty`.
+    fn binary_value(bytes: &[u8], ty: OracleType) -> OracleValue {
+        OracleValue::new(
+            None,
+            Some(bytes.to_vec()),
+            OracleTypeInfo::from_oracle_type(ty),
+            false,
+        )
+    }
+
+    /// `float` as fetched natively from a column of type `ty`.
+    fn float_value(float: f64, ty: OracleType) -> OracleValue {
+        OracleValue::new(None, None, OracleTypeInfo::from_oracle_type(ty), false).with_float(float)
+    }
+
+    /// `value` as fetched on a connection with `options`.
+    fn with_options(mut value: OracleValue, options: ValueOptions) -> OracleValue {
+        value.options = options;
+        value
+    }
+
+    /// Decodes `text` as fetched from a column of type `ty`.
+    fn decode_text(text: &str, ty: OracleType) -> Value {
+        Value::decode(text_value(text, ty).as_ref()).unwrap()
+    }
+
     #[test]
-    fn test_datetime() {}
+    fn test_datetime() {
+        let value = Timestamp::new(2024, 1, 2, 3, 4, 5, 120_000_000).unwrap();
+        assert_eq!(
+            timestamp_text(&value, &OracleType::Timestamp(6)),
+            "2024-01-02T03:04:05.120000000"
+        );
+
+        let value = value.and_tz_hm_offset(-3, -30).unwrap();
+        assert_eq!(
+            timestamp_text(&value, &OracleType::TimestampTZ(6)),
+            "2024-01-02T03:04:05.120000000-03:30"
+        );
+    }
+
+    #[test]
+    fn test_encode_datetime() {
+        for text in [
+            "2024-01-02T03:04:05.123+08:00",
+            "2024-01-02 03:04:05.123+08:00",
+            "2024-01-02T03:04:05.123000000+08:00",
+            "2024-01-01T19:04:05.123Z",
+        ] {
+            let value = parse_datetime(text).expect(text);
+            assert_eq!(value.timestamp_millis(), 1_704_135_845_123, "{text}");
+        }
+        let value = parse_datetime("2024-01-02T03:04:05.123+08:00").unwrap();
+        assert_eq!(value.offset().local_minus_utc(), 8 * 3600);
+
+        let value = from_timestamp_millis(1_704_135_845_123).unwrap();
+        assert_eq!(value.to_rfc3339(), "2024-01-01T19:04:05.123+00:00");
+    }
+
+    #[test]
+    fn test_interval_text() {
+        let value = chrono::Duration::seconds(-93_784) - chrono::Duration::milliseconds(500);
+        assert_eq!(interval_ds_text(&value), "-P1DT2H3M4.5S");
+        assert_eq!(interval_ds_text(&chrono::Duration::zero()), "P0DT0H0M0S");
+        assert_eq!(interval_ym_text(14), "P1Y2M");
+        assert_eq!(interval_ym_text(-3), "-P0Y3M");
+        let max = IntervalYM::new(999_999_999, 11).unwrap();
+        assert_eq!(interval_ym_text(interval_ym_months(&max)), "P999999999Y11M");
+    }
+
+    #[test]
+    fn test_decode_stored_bool() {
+        let stored = |text: &str, oracle_type: OracleType, encoding: Option<OracleBoolEncoding>| {
+            let options = ValueOptions {
+                stored_bools: encoding,
+                ..Default::default()
+            };
+            with_options(text_value(text, oracle_type), options)
+        };
+
+        let yes = stored("Y", OracleType::Char(1), Some(OracleBoolEncoding::YesNo));
+        assert_eq!(Value::decode(yes.as_ref()).unwrap(), Value::Bool(true));
+        let other = stored("X", OracleType::Char(1), Some(OracleBoolEncoding::YesNo));
+        assert_eq!(
+            Value::decode(other.as_ref()).unwrap(),
+            Value::String("X".to_owned())
+        );
+        assert!(bool::decode(other.as_ref()).is_err());
+
+        let flag = stored(
+            "F",
+            OracleType::Char(1),
+            Some(OracleBoolEncoding::TrueFalse),
+        );
+        assert_eq!(Value::decode(flag.as_ref()).unwrap(), Value::Bool(false));
+        assert!(!bool::decode(flag.as_ref()).unwrap());
+
+        let number = stored(
+            "1",
+            OracleType::Number(1, 0),
+            Some(OracleBoolEncoding::Number),
+        );
+        assert_eq!(Value::decode(number.as_ref()).unwrap(), Value::Bool(true));
+        let wide = stored(
+            "1",
+            OracleType::Number(2, 0),
+            Some(OracleBoolEncoding::Number),
+        );
+        assert_eq!(Value::decode(wide.as_ref()).unwrap(), Value::I32(1));
+
+        let unset = stored("Y", OracleType::Char(1), None);
+        assert_eq!(
+            Value::decode(unset.as_ref()).unwrap(),
+            Value::String("Y".to_owned())
+        );
+        assert!(bool::decode(unset.as_ref()).unwrap());
+    }
+
+    #[test]
+    fn test_decode_stored_uuid() {
+        const UUID: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+        let uuid = parse_uuid(UUID).unwrap();
+        assert_eq!(
+            uuid_text(&uuid, OracleUuidEncoding::SysGuid),
+            "67E5504410B1426F9247BB680E5FE0C8"
+        );
+        assert_eq!(uuid_text(&uuid, OracleUuidEncoding::Hyphenated), UUID);
+
+        let stored = |value: OracleValue, encoding: Option<OracleUuidEncoding>| {
+            let options = ValueOptions {
+                uuids: encoding,
+                ..Default::default()
+            };
+            Value::decode(with_options(value, options).as_ref()).unwrap()
+        };
+        let expected = Value::String(UUID.to_owned()).into_ext("Uuid");
+        let raw = || binary_value(uuid.as_bytes(), OracleType::Raw(16));
+
+        assert_eq!(stored(raw(), Some(OracleUuidEncoding::Raw16)), expected);
+        assert_eq!(stored(raw(), None), Value::Binary(uuid.as_bytes().to_vec()));
+        assert_eq!(
+            stored(text_value(UUID, OracleType::Varchar2(36)), None),
+            Value::String(UUID.to_owned())
+        );
+        assert_eq!(
+            stored(
+                text_value(UUID, OracleType::Varchar2(36)),
+                Some(OracleUuidEncoding::Hyphenated)
+            ),
+            expected
+        );
+        assert_eq!(
+            stored(
+                text_value("67E5504410B1426F9247BB680E5FE0C8", OracleType::Char(32)),
+                Some(OracleUuidEncoding::SysGuid)
+            ),
+            expected
+        );
+        assert_eq!(
+            stored(
+                text_value("not a uuid", OracleType::Varchar2(36)),
+                Some(OracleUuidEncoding::Hyphenated)
+            ),
+            Value::String("not a uuid".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_prefer_decimal() {
+        let preferring_decimal = |text: &str, oracle_type: OracleType| {
+            let options = ValueOptions {
+                prefer_decimal: true,
+                ..Default::default()
+            };
+            Value::decode(with_options(text_value(text, oracle_type), options).as_ref()).unwrap()
+        };
+        let unconstrained = OracleType::Number(0, -127);
+
+        assert_eq!(decode_text("100", unconstrained.clone()), Value::I32(100));
+        assert_eq!(
+            preferring_decimal("100", unconstrained),
+            Value::String("100".to_owned()).into_ext("Decimal")
+        );
+        // Declared integer columns are not affected.
+        assert_eq!(
+            preferring_decimal("100", OracleType::Number(10, 0)),
+            Value::I64(100)
+        );
+    }
+
+    #[test]
+    fn test_decode_native_float() {
+        let decode = |float: f64, oracle_type: OracleType| {
+            Value::decode(float_value(float, oracle_type).as_ref()).unwrap()
+        };
+
+        assert!(matches!(decode(f64::NAN, OracleType::BinaryDouble), Value::F64(v) if v.is_nan()));
+        assert!(matches!(decode(f64::NAN, OracleType::BinaryFloat), Value::F32(v) if v.is_nan()));
+        assert_eq!(
+            decode(f64::NEG_INFINITY, OracleType::BinaryFloat),
+            Value::F32(f32::NEG_INFINITY)
+        );
+        assert_eq!(decode(0.1, OracleType::BinaryDouble), Value::F64(0.1));
+        assert_eq!(
+            decode(0.1f32.into(), OracleType::BinaryFloat),
+            Value::F32(0.1)
+        );
+
+        // Text decoders read the same value.
+        let value = float_value(0.1f32.into(), OracleType::BinaryFloat);
+        assert_eq!(String::decode(value.as_ref()).unwrap(), "0.1");
+        assert_eq!(
+            Decimal::decode(value.as_ref()).unwrap().0.to_plain_string(),
+            "0.1"
+        );
+        let value = float_value(-2.25, OracleType::BinaryDouble);
+        assert_eq!(String::decode(value.as_ref()).unwrap(), "-2.25");
+        assert_eq!(
+            Decimal::decode(value.as_ref()).unwrap().0.to_plain_string(),
+            "-2.25"
+        );
+    }
 }
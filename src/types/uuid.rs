@@ -0,0 +1,76 @@
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::type_info::{OracleTypeInfo, Type};
+use crate::{OracleArgumentValue, OracleUuidEncoding, OracleValueRef};
+use oracle::sql_type::OracleType;
+use rbdc::Error;
+use rbdc::uuid::Uuid;
+
+/// Parses any textual UUID form: hyphenated, 32 hex digits, braced or URN.
+pub(crate) fn parse_uuid(value: &str) -> Result<uuid::Uuid, Error> {
+    uuid::Uuid::parse_str(value.trim())
+        .map_err(|e| Error::from(format!("invalid uuid {value}: {e}")))
+}
+
+/// Formats `uuid` for the text layouts; `RAW(16)` is bound as bytes instead.
+pub(crate) fn uuid_text(uuid: &uuid::Uuid, encoding: OracleUuidEncoding) -> String {
+    match encoding {
+        OracleUuidEncoding::SysGuid => uuid.simple().to_string().to_ascii_uppercase(),
+        OracleUuidEncoding::Raw16 | OracleUuidEncoding::Hyphenated => uuid.hyphenated().to_string(),
+    }
+}
+
+/// Reads a column stored in the connection's explicit UUID layout back as
+/// hyphenated text. Columns of another shape and values that are not a UUID
+/// are left to the usual decoding.
+pub(crate) fn decode_stored_uuid(
+    oracle_type: &OracleType,
+    value: OracleValueRef<'_>,
+) -> Option<String> {
+    let uuid = match (value.options().uuids?, oracle_type) {
+        (OracleUuidEncoding::Raw16, OracleType::Raw(16)) => {
+            uuid::Uuid::from_slice(value.blob()?).ok()?
+        }
+        (
+            OracleUuidEncoding::Hyphenated,
+            OracleType::Char(36)
+            | OracleType::NChar(36)
+            | OracleType::Varchar2(36)
+            | OracleType::NVarchar2(36),
+        )
+        | (
+            OracleUuidEncoding::SysGuid,
+            OracleType::Char(32)
+            | OracleType::NChar(32)
+            | OracleType::Varchar2(32)
+            | OracleType::NVarchar2(32),
+        ) => parse_uuid(value.text().ok()?).ok()?,
+        _ => return None,
+    };
+    Some(uuid.hyphenated().to_string())
+}
+
+impl Type for Uuid {
+    /// Text, as bound by default; a `RAW(16)` layout is applied on binding.
+    fn type_info(&self) -> OracleTypeInfo {
+        OracleTypeInfo::text()
+    }
+}
+
+impl Encode for Uuid {
+    fn encode(self, args: &mut Vec<OracleArgumentValue>) -> Result<IsNull, Error> {
+        args.push(OracleArgumentValue::Uuid(self.0));
+        Ok(IsNull::No)
+    }
+}
+
+impl Decode for Uuid {
+    /// Accepts `RAW(16)` bytes as well as any textual form.
+    fn decode(value: OracleValueRef<'_>) -> Result<Self, Error> {
+        let uuid = match value.blob() {
+            Some(bytes) => uuid::Uuid::from_slice(bytes).map_err(|e| Error::from(e.to_string()))?,
+            None => parse_uuid(value.text()?)?,
+        };
+        Ok(Uuid(uuid.hyphenated().to_string()))
+    }
+}
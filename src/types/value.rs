@@ -1,6 +1,11 @@
 use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
-use crate::{OracleArgumentValue, OracleValueRef};
+use crate::type_info::parse_oracle_type;
+use crate::types::decimal::parse_number;
+use crate::types::interval::parse_interval;
+use crate::types::json::json_text;
+use crate::{OracleArgumentValue, OracleArguments, OracleValueRef};
+use oracle::sql_type::OracleType;
 use rbdc::Error;
 use rbs::Value;
 
@@ -16,6 +21,12 @@ impl Decode for Value {
         }
 
         if let Some(oracle_type) = value.type_info().oracle_type() {
+            if let Some(flag) = crate::types::bool::decode_stored_bool(oracle_type, value) {
+                return Ok(Value::Bool(flag));
+            }
+            if let Some(uuid) = crate::types::uuid::decode_stored_uuid(oracle_type, value) {
+                return Ok(Value::String(uuid).into_ext("Uuid"));
+            }
             return crate::types::int::decode_by_oracle_type(oracle_type, value);
         }
 
@@ -97,12 +108,48 @@ impl Encode for Value {
                     Ok(IsNull::No)
                 }
                 "Decimal" => {
-                    args.push(OracleArgumentValue::Decimal(
+                    let value = value.into_string().unwrap_or_default();
+                    let value = parse_number(&value)?.to_plain_string();
+                    args.push(OracleArgumentValue::Decimal(value));
+                    Ok(IsNull::No)
+                }
+                "Json" => {
+                    args.push(OracleArgumentValue::Json(json_text(*value)?));
+                    Ok(IsNull::No)
+                }
+                "Interval" => match value.as_str() {
+                    Some(text) => {
+                        args.push(parse_interval(text)?);
+                        Ok(IsNull::No)
+                    }
+                    None => Err(Error::from("Interval expects an ISO 8601 duration")),
+                },
+                // `Ext("Out", "NUMBER")` declares an OUT bind of that type.
+                "Out" => {
+                    let oracle_type = out_type(&value)?;
+                    args.push(OracleArgumentValue::Out(oracle_type));
+                    Ok(IsNull::No)
+                }
+                // `Ext("InOut", [value, "VARCHAR2(100)"])` sends `value` and
+                // reads it back as the given type.
+                "InOut" => match *value {
+                    Value::Array(items) if items.len() == 2 => {
+                        let mut items = items.into_iter();
+                        let value = items.next().unwrap_or_default();
+                        let oracle_type = out_type(&items.next().unwrap_or_default())?;
+                        let mut arguments = OracleArguments::default();
+                        arguments.add_in_out(value, oracle_type)?;
+                        args.extend(arguments.values);
+                        Ok(IsNull::No)
+                    }
+                    _ => Err(Error::from("InOut expects [value, oracle type]")),
+                },
+                "RowId" => {
+                    args.push(OracleArgumentValue::RowId(
                         value.into_string().unwrap_or_default(),
                     ));
                     Ok(IsNull::No)
                 }
-                "Json" => Err(Error::from("unimpl")),
                 "Uuid" => {
                     args.push(OracleArgumentValue::Uuid(
                         value.into_string().unwrap_or_default(),
@@ -114,3 +161,10 @@ impl Encode for Value {
         }
     }
 }
+
+fn out_type(value: &Value) -> Result<OracleType, Error> {
+    match value.as_str() {
+        Some(name) => parse_oracle_type(name),
+        None => Err(Error::from("OUT bind type must be an oracle type name")),
+    }
+}
@@ -1,5 +1,6 @@
-use crate::OracleTypeInfo;
 use crate::types::value::MISSING_STRING_VALUE;
+use crate::{OracleBoolEncoding, OracleTypeInfo, OracleUuidEncoding};
+use oracle::sql_type::OracleType;
 use rbdc::Error;
 use std::borrow::Cow;
 use std::sync::Arc;
@@ -34,14 +35,41 @@ impl<'r> OracleValueRef<'r> {
     pub fn blob(&self) -> Option<&'r [u8]> {
         self.0.binary.as_deref()
     }
+
+    pub(crate) fn float(&self) -> Option<f64> {
+        self.0.float
+    }
+
+    pub(crate) fn options(&self) -> ValueOptions {
+        self.0.options
+    }
+}
+
+/// Per-connection conventions for binding and decoding values.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct ValueOptions {
+    /// Representation `bool` arguments are bound with.
+    pub(crate) bools: OracleBoolEncoding,
+    /// Convention flag columns are stored in, decoded back to `bool`. Only
+    /// set when configured explicitly, so plain `NUMBER(1)` and `CHAR(1)`
+    /// columns keep their type otherwise.
+    pub(crate) stored_bools: Option<OracleBoolEncoding>,
+    /// Layout UUIDs are bound in and, when set, decoded back from.
+    pub(crate) uuids: Option<OracleUuidEncoding>,
+    /// Decode unconstrained `NUMBER`s as `Decimal` even when integral.
+    pub(crate) prefer_decimal: bool,
 }
 
 #[derive(Debug, Clone)]
 pub struct OracleValue {
     pub(crate) text: Option<Arc<str>>,
     pub(crate) binary: Option<Arc<[u8]>>,
+    /// `BINARY_FLOAT` and `BINARY_DOUBLE` values, fetched natively; `text`
+    /// holds their text form for decoders that parse it.
+    pub(crate) float: Option<f64>,
     pub(crate) type_info: OracleTypeInfo,
     pub(crate) is_null: bool,
+    pub(crate) options: ValueOptions,
 }
 
 impl OracleValue {
@@ -54,11 +82,26 @@ impl OracleValue {
         Self {
             text: text.map(Into::into),
             binary: binary.map(Into::into),
+            float: None,
             type_info,
             is_null,
+            options: ValueOptions::default(),
         }
     }
 
+    /// Stores a natively fetched `BINARY_FLOAT` or `BINARY_DOUBLE` together
+    /// with its text form, so `String` and `Decimal` still decode it.
+    pub(crate) fn with_float(mut self, float: f64) -> Self {
+        let text = match self.type_info.oracle_type() {
+            // Widened exactly from `f32`; print it at its own precision.
+            Some(OracleType::BinaryFloat) => (float as f32).to_string(),
+            _ => float.to_string(),
+        };
+        self.text = Some(text.into());
+        self.float = Some(float);
+        self
+    }
+
     pub fn as_ref(&self) -> OracleValueRef<'_> {
         OracleValueRef::value(self)
     }
@@ -1,15 +1,56 @@
 use oracle::sql_type::OracleType;
+use rbdc::datetime::DateTime;
+use rbdc::decimal::Decimal;
+use rbdc::json::{Json, JsonV};
+use rbdc::timestamp::Timestamp;
+use rbdc::uuid::Uuid;
 use rbdc_oracle::decode::Decode;
+use rbdc_oracle::type_info::Type;
 use rbdc_oracle::{OracleArgumentValue, OracleArguments, OracleTypeInfo, OracleValue};
 use rbs::Value;
+use std::str::FromStr;
+
+/// Decodes `text` as fetched from a column of type `ty`.
+fn decode_text(text: &str, ty: OracleType) -> Value {
+    let value = OracleValue::new(
+        Some(text.to_owned()),
+        None,
+        OracleTypeInfo::from_oracle_type(ty),
+        false,
+    );
+    Value::decode(value.as_ref()).expect(text)
+}
+
+fn decimal(text: &str) -> Value {
+    Value::String(text.to_owned()).into_ext("Decimal")
+}
 
 #[test]
-fn test_compat_encode_bool_matches_old_driver() {
+fn test_encode_bool() {
     let args = OracleArguments::from_args(vec![Value::Bool(true)]).expect("encode bool");
-    assert!(matches!(
-        args.values(),
-        [OracleArgumentValue::String(v)] if v == "true"
-    ));
+    assert!(matches!(args.values(), [OracleArgumentValue::Bool(true)]));
+}
+
+#[test]
+fn test_decode_native_bool() {
+    let value = OracleValue::new(
+        Some("FALSE".to_owned()),
+        None,
+        OracleTypeInfo::from_oracle_type(OracleType::Boolean),
+        false,
+    );
+    assert_eq!(
+        Value::decode(value.as_ref()).expect("decode bool"),
+        Value::Bool(false)
+    );
+
+    let value = OracleValue::new(
+        Some("TRUE".to_owned()),
+        None,
+        OracleTypeInfo::from_oracle_type(OracleType::Boolean),
+        false,
+    );
+    assert!(bool::decode(value.as_ref()).expect("decode bool"));
 }
 
 #[test]
@@ -64,3 +105,491 @@ fn test_compat_decode_blob() {
     let decoded = Value::decode(value.as_ref()).expect("decode blob");
     assert_eq!(decoded, Value::Binary(vec![1, 2, 3]));
 }
+
+#[test]
+fn test_decode_raw() {
+    for oracle_type in [OracleType::Raw(16), OracleType::LongRaw] {
+        let value = OracleValue::new(
+            None,
+            Some(vec![0xde, 0xad]),
+            OracleTypeInfo::from_oracle_type(oracle_type),
+            false,
+        );
+        assert_eq!(
+            Value::decode(value.as_ref()).expect("decode raw"),
+            Value::Binary(vec![0xde, 0xad])
+        );
+    }
+}
+
+#[test]
+fn test_uuid_round_trip() {
+    let uuid = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+    let args =
+        OracleArguments::from_args(vec![Value::from(Uuid(uuid.to_owned()))]).expect("encode uuid");
+    assert!(matches!(
+        args.values(),
+        [OracleArgumentValue::Uuid(value)] if value == uuid
+    ));
+    assert_eq!(Uuid(uuid.to_owned()).type_info().name(), "VARCHAR2");
+    assert_eq!(
+        Value::from(Uuid(uuid.to_owned())).type_info().name(),
+        "VARCHAR2"
+    );
+
+    let bytes = vec![
+        0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f, 0xe0,
+        0xc8,
+    ];
+    let value = OracleValue::new(
+        None,
+        Some(bytes),
+        OracleTypeInfo::from_oracle_type(OracleType::Raw(16)),
+        false,
+    );
+    assert_eq!(
+        Uuid::decode(value.as_ref()).expect("decode raw uuid").0,
+        uuid
+    );
+
+    let value = OracleValue::new(
+        Some("67E5504410B1426F9247BB680E5FE0C8".to_owned()),
+        None,
+        OracleTypeInfo::from_oracle_type(OracleType::Varchar2(32)),
+        false,
+    );
+    assert_eq!(
+        Uuid::decode(value.as_ref()).expect("decode hex uuid").0,
+        uuid
+    );
+}
+
+#[test]
+fn test_rowid_round_trip() {
+    let rowid = "AAAR3sAAEAAAACXAAA";
+    let value = OracleValue::new(
+        Some(rowid.to_owned()),
+        None,
+        OracleTypeInfo::from_oracle_type(OracleType::Rowid),
+        false,
+    );
+    let decoded = Value::decode(value.as_ref()).expect("decode rowid");
+    assert_eq!(decoded, Value::String(rowid.to_owned()).into_ext("RowId"));
+
+    let args = OracleArguments::from_args(vec![decoded]).expect("encode rowid");
+    assert!(matches!(
+        args.values(),
+        [OracleArgumentValue::RowId(value)] if value == rowid
+    ));
+
+    let args = OracleArguments::from_args(vec![Value::Ext(
+        "Out",
+        Box::new(Value::String("UROWID(4000)".to_owned())),
+    )])
+    .expect("encode urowid out bind");
+    assert!(matches!(
+        args.values(),
+        [OracleArgumentValue::Out(OracleType::Rowid)]
+    ));
+}
+
+#[test]
+fn test_encode_out_bind() {
+    let args = OracleArguments::from_args(vec![
+        Value::Ext("Out", Box::new(Value::String("number(10, 2)".to_owned()))),
+        Value::Ext(
+            "InOut",
+            Box::new(Value::Array(vec![
+                Value::I64(7),
+                Value::String("TIMESTAMP WITH TIME ZONE".to_owned()),
+            ])),
+        ),
+    ])
+    .expect("encode out binds");
+
+    assert!(matches!(
+        args.values(),
+        [
+            OracleArgumentValue::Out(OracleType::Number(10, 2)),
+            OracleArgumentValue::InOut(value, OracleType::TimestampTZ(6)),
+        ] if matches!(**value, OracleArgumentValue::I64(7))
+    ));
+}
+
+#[test]
+fn test_encode_out_bind_rejects_unknown_type() {
+    let result = OracleArguments::from_args(vec![Value::Ext(
+        "Out",
+        Box::new(Value::String("varchar2(abc)".to_owned())),
+    )]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_encode_ref_cursor_out_bind() {
+    let args = OracleArguments::from_args(vec![Value::Ext(
+        "Out",
+        Box::new(Value::String("sys_refcursor".to_owned())),
+    )])
+    .expect("encode ref cursor");
+
+    assert!(matches!(
+        args.values(),
+        [OracleArgumentValue::Out(OracleType::RefCursor)]
+    ));
+}
+
+#[test]
+fn test_decode_timestamp_tz() {
+    let value = OracleValue::new(
+        Some("2024-01-02T03:04:05.123456789+09:30".to_owned()),
+        None,
+        OracleTypeInfo::from_oracle_type(OracleType::TimestampTZ(9)),
+        false,
+    );
+
+    let decoded = Value::decode(value.as_ref()).expect("decode timestamp tz");
+    let expected = DateTime::from_str("2024-01-02T03:04:05.123456789+09:30").expect("datetime");
+    assert_eq!(decoded, Value::from(expected.clone()));
+
+    let datetime = DateTime::decode(value.as_ref()).expect("decode datetime");
+    assert_eq!(datetime.offset(), 9 * 3600 + 30 * 60);
+    assert_eq!(datetime.nano(), 123_456_789);
+
+    let timestamp = Timestamp::decode(value.as_ref()).expect("decode rbdc timestamp");
+    assert_eq!(timestamp, Timestamp(expected.unix_timestamp_millis()));
+}
+
+#[test]
+fn test_interval_round_trip() {
+    let args = OracleArguments::from_args(vec![
+        Value::Ext(
+            "Interval",
+            Box::new(Value::String("P1DT2H3M4.5S".to_owned())),
+        ),
+        Value::Ext("Interval", Box::new(Value::String("-P1Y2M".to_owned()))),
+        Value::Ext(
+            "Interval",
+            Box::new(Value::String("+1 02:03:04.5".to_owned())),
+        ),
+    ])
+    .expect("encode intervals");
+    let expected = chrono::Duration::seconds(93_784) + chrono::Duration::milliseconds(500);
+    assert!(matches!(
+        args.values(),
+        [
+            OracleArgumentValue::IntervalDS(ds),
+            OracleArgumentValue::IntervalYM(-14),
+            OracleArgumentValue::IntervalDS(literal),
+        ] if *ds == expected && *literal == expected
+    ));
+
+    let value = OracleValue::new(
+        Some("P1DT2H3M4.5S".to_owned()),
+        None,
+        OracleTypeInfo::from_oracle_type(OracleType::IntervalDS(2, 6)),
+        false,
+    );
+    assert_eq!(
+        Value::decode(value.as_ref()).expect("decode interval"),
+        Value::String("P1DT2H3M4.5S".to_owned()).into_ext("Interval")
+    );
+    assert_eq!(
+        std::time::Duration::decode(value.as_ref()).expect("decode duration"),
+        std::time::Duration::from_millis(93_784_500)
+    );
+}
+
+#[test]
+fn test_interval_bounds() {
+    let interval = |text: &str| {
+        OracleArguments::from_args(vec![Value::Ext(
+            "Interval",
+            Box::new(Value::String(text.to_owned())),
+        )])
+        .map(|args| args.values()[0].clone())
+    };
+
+    // `INTERVAL YEAR(9) TO MONTH` holds more months than an `i32`.
+    for text in ["+999999999-11", "-P999999999Y11M"] {
+        assert!(matches!(
+            interval(text),
+            Ok(OracleArgumentValue::IntervalYM(months)) if months.abs() == 11_999_999_999
+        ));
+    }
+    let max_days = chrono::Duration::days(999_999_999) + chrono::Duration::seconds(86_399);
+    assert!(matches!(
+        interval("+999999999 23:59:59"),
+        Ok(OracleArgumentValue::IntervalDS(value)) if value == max_days
+    ));
+
+    for text in [
+        "P9223372036854775807Y",
+        "+9223372036854775807-0",
+        "+9223372036854775807 00:00:00",
+        "+200000000000 00:00:00",
+        "P200000000000D",
+        "PT9223372036854775807H",
+    ] {
+        assert!(interval(text).is_err(), "{text}");
+    }
+}
+
+#[test]
+fn test_json_round_trip() {
+    let mut map = rbs::value::map::ValueMap::new();
+    map.insert(Value::String("a".to_owned()), Value::I64(1));
+    let args = OracleArguments::from_args(vec![
+        Value::Ext("Json", Box::new(Value::String(r#"{"a":1}"#.to_owned()))),
+        Value::Ext("Json", Box::new(Value::Map(map))),
+    ])
+    .expect("encode json");
+    assert!(matches!(
+        args.values(),
+        [OracleArgumentValue::Json(text), OracleArgumentValue::Json(map)]
+            if text == r#"{"a":1}"# && map == r#"{"a":1}"#
+    ));
+
+    // Documents past 4000 bytes are bound, and reported, as `CLOB`.
+    let small = Json(r#"{"a":1}"#.to_owned());
+    let large = Json(format!(r#"{{"a":"{}"}}"#, "x".repeat(4000)));
+    assert_eq!(small.type_info().name(), "VARCHAR2");
+    assert_eq!(large.type_info().name(), "CLOB");
+    assert_eq!(Value::from(small).type_info().name(), "VARCHAR2");
+    assert_eq!(Value::from(large).type_info().name(), "CLOB");
+
+    // Documents come back as the text of `IS JSON` columns or of
+    // `JSON_SERIALIZE`, which returns `VARCHAR2(4000)` unless told otherwise.
+    let document = r#"{"a":[1,"b",null]}"#;
+    for oracle_type in [OracleType::Varchar2(4000), OracleType::CLOB] {
+        let value = OracleValue::new(
+            Some(document.to_owned()),
+            None,
+            OracleTypeInfo::from_oracle_type(oracle_type),
+            false,
+        );
+        assert_eq!(
+            Value::decode(value.as_ref()).expect("decode json"),
+            Value::String(document.to_owned())
+        );
+        assert_eq!(
+            Json::decode(value.as_ref()).expect("decode json").0,
+            document
+        );
+
+        let JsonV(decoded) =
+            JsonV::<Document>::decode(value.as_ref()).expect("decode json document");
+        assert_eq!(
+            decoded,
+            Document {
+                a: vec![Value::I64(1), Value::String("b".to_owned()), Value::Null],
+            }
+        );
+    }
+
+    let value = OracleValue::new(
+        Some("{\"a\":".to_owned()),
+        None,
+        OracleTypeInfo::from_oracle_type(OracleType::CLOB),
+        false,
+    );
+    assert!(Json::decode(value.as_ref()).is_err());
+}
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Document {
+    a: Vec<Value>,
+}
+
+#[test]
+fn test_decode_numeric_matrix() {
+    assert_eq!(
+        decode_text("9223372036854775807", OracleType::Int64),
+        Value::I64(i64::MAX)
+    );
+    assert_eq!(
+        decode_text("18446744073709551615", OracleType::UInt64),
+        Value::U64(u64::MAX)
+    );
+    assert_eq!(decode_text("1.5", OracleType::BinaryFloat), Value::F32(1.5));
+    assert_eq!(
+        decode_text("-2.25", OracleType::BinaryDouble),
+        Value::F64(-2.25)
+    );
+    assert_eq!(decode_text("0.5", OracleType::Float(126)), Value::F64(0.5));
+
+    assert_eq!(
+        decode_text("-999999999", OracleType::Number(9, 0)),
+        Value::I32(-999_999_999)
+    );
+    assert_eq!(
+        decode_text("9999999999", OracleType::Number(10, 0)),
+        Value::I64(9_999_999_999)
+    );
+    assert_eq!(
+        decode_text("123400", OracleType::Number(8, -2)),
+        Value::I64(123_400)
+    );
+    assert_eq!(
+        decode_text("999999999999999999", OracleType::Number(18, 0)),
+        Value::I64(999_999_999_999_999_999)
+    );
+    // Past 18 digits the column type decides, not the value, so small and
+    // large values of one column decode alike.
+    assert_eq!(decode_text("42", OracleType::Number(19, 0)), decimal("42"));
+    assert_eq!(
+        decode_text("18446744073709551615", OracleType::Number(20, 0)),
+        decimal("18446744073709551615")
+    );
+    assert_eq!(
+        decode_text(
+            "-99999999999999999999999999999999999999",
+            OracleType::Number(38, 0)
+        ),
+        decimal("-99999999999999999999999999999999999999")
+    );
+
+    let unconstrained = OracleType::Number(0, -127);
+    assert_eq!(decode_text("12", unconstrained.clone()), Value::I32(12));
+    assert_eq!(
+        decode_text("12345678901", unconstrained.clone()),
+        Value::I64(12_345_678_901)
+    );
+    assert_eq!(
+        decode_text("1E+20", unconstrained.clone()),
+        decimal("100000000000000000000")
+    );
+    assert_eq!(decode_text("0.125", unconstrained), decimal("0.125"));
+}
+
+#[test]
+fn test_numeric_round_trip() {
+    let args = OracleArguments::from_args(vec![
+        Value::I64(i64::MIN),
+        Value::U64(u64::MAX),
+        Value::F32(1.5),
+        Value::F64(-2.25),
+        Value::String("12345678901234567890.5".to_owned()).into_ext("Decimal"),
+    ])
+    .expect("encode numbers");
+    let [
+        OracleArgumentValue::I64(i64_value),
+        OracleArgumentValue::U64(u64_value),
+        OracleArgumentValue::F32(f32_value),
+        OracleArgumentValue::F64(f64_value),
+        OracleArgumentValue::Decimal(decimal_value),
+    ] = args.values()
+    else {
+        panic!("unexpected arguments: {:?}", args.values());
+    };
+
+    assert_eq!(
+        decode_text(&i64_value.to_string(), OracleType::Number(19, 0)),
+        decimal(&i64::MIN.to_string())
+    );
+    assert_eq!(
+        decode_text(&u64_value.to_string(), OracleType::Number(20, 0)),
+        decimal(&u64::MAX.to_string())
+    );
+    assert_eq!(
+        decode_text(&f32_value.to_string(), OracleType::BinaryFloat),
+        Value::F32(1.5)
+    );
+    assert_eq!(
+        decode_text(&f64_value.to_string(), OracleType::BinaryDouble),
+        Value::F64(-2.25)
+    );
+    assert_eq!(
+        decode_text(decimal_value, OracleType::Number(38, 1)),
+        decimal("12345678901234567890.5")
+    );
+}
+
+#[test]
+fn test_binary_float_special_values() {
+    let args =
+        OracleArguments::from_args(vec![Value::F32(f32::NAN), Value::F64(f64::NEG_INFINITY)])
+            .expect("encode floats");
+    assert!(matches!(
+        args.values(),
+        [OracleArgumentValue::F32(nan), OracleArgumentValue::F64(f64::NEG_INFINITY)]
+            if nan.is_nan()
+    ));
+
+    // Oracle's `TO_CHAR` spelling of the special values.
+    assert!(matches!(decode_text("Nan", OracleType::BinaryDouble), Value::F64(v) if v.is_nan()));
+    assert!(matches!(decode_text("Nan", OracleType::BinaryFloat), Value::F32(v) if v.is_nan()));
+    assert_eq!(
+        decode_text("Inf", OracleType::BinaryDouble),
+        Value::F64(f64::INFINITY)
+    );
+    assert_eq!(
+        decode_text("-Inf", OracleType::BinaryFloat),
+        Value::F32(f32::NEG_INFINITY)
+    );
+}
+
+#[test]
+fn test_decimal_lossless() {
+    let digits = "1234567890123456789012345678.9012345678";
+    assert_eq!(
+        decode_text(digits, OracleType::Number(38, 10)),
+        decimal(digits)
+    );
+    assert_eq!(
+        decode_text(
+            "0.00000000000000000000000000000000000001",
+            OracleType::Number(0, -127)
+        ),
+        decimal("0.00000000000000000000000000000000000001")
+    );
+    assert_eq!(
+        decode_text("1E+30", OracleType::Number(38, 2)),
+        decimal("1000000000000000000000000000000")
+    );
+    assert_eq!(
+        decode_text("12300000", OracleType::Number(3, -5)),
+        Value::I32(12_300_000)
+    );
+    // `NUMBER(*, 0)` holds 38 digits, like `NUMBER(38)`.
+    assert_eq!(decode_text("42", OracleType::Number(0, 0)), decimal("42"));
+
+    let value = OracleValue::new(
+        Some(digits.to_owned()),
+        None,
+        OracleTypeInfo::from_oracle_type(OracleType::Number(38, 10)),
+        false,
+    );
+    let decoded = Decimal::decode(value.as_ref()).expect("decode decimal");
+    assert_eq!(decoded.0.to_plain_string(), digits);
+
+    let args = OracleArguments::from_args(vec![Value::from(decoded)]).expect("encode decimal");
+    assert!(matches!(
+        args.values(),
+        [OracleArgumentValue::Decimal(value)] if value == digits
+    ));
+
+    // An explicit plus sign is accepted and dropped.
+    let args = OracleArguments::from_args(vec![decimal("+5"), decimal("+0.25")])
+        .expect("encode signed decimals");
+    assert!(matches!(
+        args.values(),
+        [OracleArgumentValue::Decimal(five), OracleArgumentValue::Decimal(quarter)]
+            if five == "5" && quarter == "0.25"
+    ));
+
+    // Grouping and `,` separators are ambiguous, so they are never guessed.
+    for text in ["1,000", "-123,45", "1 000", "1_000", "+-5", "++5", ""] {
+        let value = OracleValue::new(
+            Some(text.to_owned()),
+            None,
+            OracleTypeInfo::from_oracle_type(OracleType::Number(10, 2)),
+            false,
+        );
+        assert!(Value::decode(value.as_ref()).is_err(), "{text}");
+        assert!(
+            OracleArguments::from_args(vec![decimal(text)]).is_err(),
+            "{text}"
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use rbdc_oracle::{OracleError, OracleErrorKind};
+
+#[test]
+fn test_error_code_and_kind() {
+    let error = OracleError::new("ORA-00001: unique constraint (SCOTT.PK_EMP) violated");
+    assert_eq!(error.code(), Some(1));
+    assert_eq!(error.kind(), OracleErrorKind::UniqueViolation);
+
+    let error = OracleError::new("ORA-03113: end-of-file on communication channel");
+    assert_eq!(error.code(), Some(3113));
+    assert_eq!(error.kind(), OracleErrorKind::ConnectionLost);
+
+    let error = OracleError::new("DPI-1080: connection was closed by ORA-3113");
+    assert_eq!(error.code(), None);
+    assert_eq!(error.kind(), OracleErrorKind::ConnectionLost);
+
+    let error = OracleError::new("invalid type conversion from NUMBER to DATE");
+    assert_eq!(error.code(), None);
+    assert_eq!(error.kind(), OracleErrorKind::Driver);
+}
+
+#[test]
+// The tuple variants are the only public way to build an `oracle::Error`
+// carrying a `DbError`.
+#[allow(deprecated)]
+fn test_error_from_db_error() {
+    let error = OracleError::from(oracle::Error::OciError(oracle::DbError::new(
+        942,
+        14,
+        "ORA-00942: table or view does not exist",
+        "dpiStmt_execute",
+        "execute",
+    )));
+    assert_eq!(error.message(), "ORA-00942: table or view does not exist");
+    assert_eq!(error.code(), Some(942));
+    assert_eq!(error.offset(), 14);
+    assert_eq!(error.fn_name(), Some("dpiStmt_execute"));
+    assert_eq!(error.kind(), OracleErrorKind::Database);
+
+    let error = OracleError::from(oracle::Error::DpiError(oracle::DbError::new(
+        0,
+        0,
+        "DPI-1080: connection was closed by ORA-3113",
+        "dpiStmt_execute",
+        "check connected",
+    )));
+    assert_eq!(error.code(), None);
+    assert_eq!(error.kind(), OracleErrorKind::ConnectionLost);
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_error_recover_from_rbdc() {
+    let error = OracleError::from(oracle::Error::OciError(oracle::DbError::new(
+        942,
+        14,
+        "ORA-00942: table or view does not exist",
+        "dpiStmt_execute",
+        "execute",
+    )));
+    let rbdc_error = rbdc::Error::from(error.clone());
+    assert_eq!(
+        rbdc_error.to_string(),
+        "ORA-00942: table or view does not exist (offset: 14, fn: dpiStmt_execute)"
+    );
+
+    let recovered = OracleError::from(&rbdc_error);
+    assert_eq!(recovered, error);
+    assert_eq!(recovered.code(), Some(942));
+    assert_eq!(recovered.offset(), 14);
+    assert_eq!(recovered.fn_name(), Some("dpiStmt_execute"));
+    assert_eq!(recovered.kind(), OracleErrorKind::Database);
+
+    let error = OracleError::new("ORA-00942: table or view does not exist").with_offset(14);
+    let rbdc_error = rbdc::Error::from(error.clone());
+    assert_eq!(
+        rbdc_error.to_string(),
+        "ORA-00942: table or view does not exist (offset: 14)"
+    );
+    assert_eq!(OracleError::from(&rbdc_error), error);
+}
+
+#[test]
+fn test_error_recover_without_offset() {
+    let rbdc_error = rbdc::Error::from(OracleError::new(
+        "ORA-02291: integrity constraint (SCOTT.FK_DEPT) violated - parent key not found",
+    ));
+
+    let recovered = OracleError::from(&rbdc_error);
+    assert_eq!(recovered.code(), Some(2291));
+    assert_eq!(recovered.offset(), 0);
+    assert_eq!(recovered.fn_name(), None);
+    assert_eq!(recovered.kind(), OracleErrorKind::ForeignKeyViolation);
+
+    // Parentheses closing the message itself are not taken for details.
+    let error = OracleError::new(
+        r#"ORA-12899: value too large for column "SCOTT"."EMP"."ENAME" (actual: 20, maximum: 10)"#,
+    );
+    let recovered = OracleError::from(&rbdc::Error::from(error.clone()));
+    assert_eq!(recovered, error);
+}
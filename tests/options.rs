@@ -1,5 +1,7 @@
 use rbdc::db::ConnectOptions;
-use rbdc_oracle::OracleConnectOptions;
+use rbdc_oracle::{
+    OracleBoolEncoding, OracleConnectOptions, OracleIsolationLevel, OracleUuidEncoding,
+};
 
 #[test]
 fn test_set_uri() {
@@ -26,6 +28,21 @@ fn test_default_builder() {
     assert_eq!(options.statement_cache_capacity, 100);
     assert_eq!(options.row_channel_size, 50);
     assert_eq!(options.command_channel_size, 50);
+    assert!(options.autocommit);
+    assert_eq!(options.bool_fallback, OracleBoolEncoding::Number);
+    assert_eq!(options.bool_encoding, None);
+}
+
+#[test]
+fn test_autocommit() {
+    let options = OracleConnectOptions::new().autocommit(false);
+    assert!(!options.autocommit);
+
+    let options: OracleConnectOptions =
+        r#"{"username":"a","password":"b","connect_string":"//db/service","autocommit":false}"#
+            .parse()
+            .expect("oracle json");
+    assert!(!options.autocommit);
 }
 
 #[test]
@@ -39,3 +56,77 @@ fn test_with_credentials() {
     assert_eq!(options.row_channel_size, 50);
     assert_eq!(options.command_channel_size, 50);
 }
+
+#[test]
+fn test_isolation_level() {
+    let options = OracleConnectOptions::new().isolation_level(OracleIsolationLevel::Serializable);
+    assert_eq!(
+        options.isolation_level,
+        Some(OracleIsolationLevel::Serializable)
+    );
+
+    let options: OracleConnectOptions =
+        r#"{"username":"a","password":"b","connect_string":"//db/service","isolation_level":"read_only"}"#
+            .parse()
+            .expect("oracle json");
+    assert_eq!(
+        options.isolation_level,
+        Some(OracleIsolationLevel::ReadOnly)
+    );
+
+    let level: OracleIsolationLevel = "isolation level read committed".parse().expect("level");
+    assert_eq!(level, OracleIsolationLevel::ReadCommitted);
+    assert!("repeatable read".parse::<OracleIsolationLevel>().is_err());
+}
+
+#[test]
+fn test_bool_fallback() {
+    let options = OracleConnectOptions::new().bool_fallback(OracleBoolEncoding::YesNo);
+    assert_eq!(options.bool_fallback, OracleBoolEncoding::YesNo);
+
+    let options: OracleConnectOptions =
+        r#"{"username":"a","password":"b","connect_string":"//db/service","bool_fallback":"yes_no"}"#
+            .parse()
+            .expect("oracle json");
+    assert_eq!(options.bool_fallback, OracleBoolEncoding::YesNo);
+}
+
+#[test]
+fn test_bool_encoding() {
+    let options = OracleConnectOptions::new().bool_encoding(OracleBoolEncoding::TrueFalse);
+    assert_eq!(options.bool_encoding, Some(OracleBoolEncoding::TrueFalse));
+
+    let options: OracleConnectOptions =
+        r#"{"username":"a","password":"b","connect_string":"//db/service","bool_encoding":"native"}"#
+            .parse()
+            .expect("oracle json");
+    assert_eq!(options.bool_encoding, Some(OracleBoolEncoding::Native));
+}
+
+#[test]
+fn test_uuid_encoding() {
+    let options = OracleConnectOptions::new();
+    assert_eq!(options.uuid_encoding, None);
+
+    let options = options.uuid_encoding(OracleUuidEncoding::Hyphenated);
+    assert_eq!(options.uuid_encoding, Some(OracleUuidEncoding::Hyphenated));
+
+    let options: OracleConnectOptions =
+        r#"{"username":"a","password":"b","connect_string":"//db/service","uuid_encoding":"sys_guid"}"#
+            .parse()
+            .expect("oracle json");
+    assert_eq!(options.uuid_encoding, Some(OracleUuidEncoding::SysGuid));
+}
+
+#[test]
+fn test_prefer_decimal() {
+    let options = OracleConnectOptions::new();
+    assert!(!options.prefer_decimal);
+    assert!(options.prefer_decimal(true).prefer_decimal);
+
+    let options: OracleConnectOptions =
+        r#"{"username":"a","password":"b","connect_string":"//db/service","prefer_decimal":true}"#
+            .parse()
+            .expect("oracle json");
+    assert!(options.prefer_decimal);
+}